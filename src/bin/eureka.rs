@@ -3,19 +3,86 @@ extern crate clap;
 extern crate pretty_env_logger;
 extern crate termcolor;
 
-use clap::ArgAction;
-use std::io;
+use chrono::NaiveDate;
+use clap::{ArgAction, ArgMatches};
+use std::io::Read;
+use std::path::PathBuf;
+use std::{env, fs, io, process};
 
-use eureka::config_manager::ConfigManager;
+use eureka::config_manager::{ConfigManagement, ConfigManager, ConfigType, Profile};
 use eureka::git::Git;
+use eureka::idea::IdeaStatus;
 use eureka::printer::Printer;
 use eureka::program_access::ProgramAccess;
 use eureka::reader::Reader;
-use eureka::{Eureka, EurekaOptions};
-use log::error;
+use eureka::{
+    AddOptions, ConfigCommand, Eureka, EurekaCommand, ListOptions, ProfileCommand, SearchOptions,
+    SortBy, ViewOptions,
+};
+use log::{debug, error};
+
+const CMD_ADD: &str = "add";
+const CMD_VIEW: &str = "view";
+const CMD_CONFIG: &str = "config";
+const CMD_CONFIG_CLEAR: &str = "clear";
+const CMD_CONFIG_PROFILES: &str = "profiles";
+const CMD_CONFIG_SHOW: &str = "show";
+const CMD_CONFIG_GET: &str = "get";
+const CMD_CONFIG_SET: &str = "set";
+const CMD_CONFIG_UNSET: &str = "unset";
+const CMD_CONFIG_LIST: &str = "list";
+const CMD_CONFIG_EDIT: &str = "edit";
+const CMD_PROFILES_LIST: &str = "list";
+const CMD_PROFILES_ADD: &str = "add";
+const CMD_PROFILES_RM: &str = "rm";
+const CMD_PROFILES_DEFAULT: &str = "default";
+const CMD_SYNC: &str = "sync";
+const CMD_DOCTOR: &str = "doctor";
+const CMD_LIST: &str = "list";
+const CMD_SEARCH: &str = "search";
+const CMD_TAGS: &str = "tags";
+const CMD_EDIT: &str = "edit";
+const CMD_STATUS: &str = "status";
+const CMD_TAG: &str = "tag";
+const CMD_RM: &str = "rm";
+const CMD_ARCHIVE: &str = "archive";
+const CMD_UNDO: &str = "undo";
+const CMD_BROWSE: &str = "browse";
 
 const ARG_CLEAR_CONFIG: &str = "clear-config";
 const ARG_VIEW: &str = "view";
+const ARG_MESSAGE: &str = "message";
+const ARG_BODY_FILE: &str = "body-file";
+const ARG_OFFLINE: &str = "offline";
+const ARG_TEMPLATE: &str = "template";
+const ARG_SINCE: &str = "since";
+const ARG_UNTIL: &str = "until";
+const ARG_TAG: &str = "tag";
+const ARG_STATUS: &str = "status";
+const ARG_LIMIT: &str = "limit";
+const ARG_SORT: &str = "sort";
+const ARG_REVERSE: &str = "reverse";
+const ARG_QUERY: &str = "query";
+const ARG_IGNORE_CASE: &str = "ignore-case";
+const ARG_REGEX: &str = "regex";
+const ARG_COMMITS: &str = "commits";
+const ARG_IDEA: &str = "idea";
+const ARG_GROUP: &str = "group";
+const ARG_NEW_STATUS: &str = "new-status";
+const ARG_NEW_TAGS: &str = "new-tags";
+const ARG_EDITOR: &str = "editor";
+const ARG_PAGER: &str = "pager";
+const ARG_RAW: &str = "raw";
+const ARG_PROFILE: &str = "profile";
+const ARG_NAME: &str = "name";
+const ARG_REPO: &str = "repo";
+const ARG_BRANCH: &str = "branch";
+const ARG_REMOTE: &str = "remote";
+const ARG_STORAGE: &str = "storage";
+const ARG_FILE: &str = "file";
+const ARG_ORIGIN: &str = "origin";
+const ARG_KEY: &str = "key";
+const ARG_VALUE: &str = "value";
 
 fn main() {
     pretty_env_logger::init();
@@ -24,40 +91,603 @@ fn main() {
         .author(crate_authors!())
         .version(crate_version!())
         .about("Input and store your ideas without leaving the terminal")
+        .args_conflicts_with_subcommands(true)
+        // Running without a subcommand captures an idea, same as `add`
+        .args(add_args())
+        .arg(
+            clap::Arg::new(ARG_EDITOR)
+                .long(ARG_EDITOR)
+                .value_name("COMMAND")
+                .global(true)
+                .help(
+                    "Edit ideas with COMMAND instead of the configured editor or $VISUAL/$EDITOR",
+                ),
+        )
+        .arg(
+            clap::Arg::new(ARG_PAGER)
+                .long(ARG_PAGER)
+                .value_name("COMMAND")
+                .global(true)
+                .help("View ideas with COMMAND instead of the configured pager or $PAGER"),
+        )
+        .arg(
+            clap::Arg::new(ARG_PROFILE)
+                .long(ARG_PROFILE)
+                .short(ARG_PROFILE.chars().next().unwrap())
+                .value_name("NAME")
+                .global(true)
+                .help("Use the idea repo of profile NAME instead of the default profile"),
+        )
         .arg(
             clap::Arg::new(ARG_CLEAR_CONFIG)
                 .long(ARG_CLEAR_CONFIG)
                 .action(ArgAction::SetTrue)
-                .help("Clear your stored configuration"),
+                .hide(true)
+                .help("Clear your stored configuration (use `config clear`)"),
         )
         .arg(
             clap::Arg::new(ARG_VIEW)
                 .long(ARG_VIEW)
                 .short(ARG_VIEW.chars().next().unwrap())
                 .action(ArgAction::SetTrue)
-                .help("View ideas with your $PAGER env variable. If unset use less"),
+                .hide(true)
+                .help("View ideas with your $PAGER env variable (use `view`)"),
+        )
+        .subcommand(
+            clap::Command::new(CMD_ADD)
+                .about("Capture a new idea (default when no command is given)")
+                .args(add_args()),
+        )
+        .subcommand(
+            clap::Command::new(CMD_VIEW)
+                .about("View ideas with your $PAGER env variable. If unset use less")
+                .arg(status_arg("Only view ideas with this status"))
+                .arg(
+                    clap::Arg::new(ARG_RAW)
+                        .long(ARG_RAW)
+                        .action(ArgAction::SetTrue)
+                        .help("Show the Markdown as is instead of formatting it"),
+                )
+                .arg(group_arg()),
+        )
+        .subcommand(
+            clap::Command::new(CMD_CONFIG)
+                .about("Manage your stored configuration")
+                .subcommand_required(true)
+                .subcommand(
+                    clap::Command::new(CMD_CONFIG_CLEAR).about("Clear your stored configuration"),
+                )
+                .subcommand(
+                    clap::Command::new(CMD_CONFIG_SHOW)
+                        .about("Show every setting in effect here")
+                        .arg(
+                            clap::Arg::new(ARG_ORIGIN)
+                                .long(ARG_ORIGIN)
+                                .action(ArgAction::SetTrue)
+                                .help("Also show where each setting comes from"),
+                        ),
+                )
+                .subcommand(
+                    clap::Command::new(CMD_CONFIG_GET)
+                        .about("Print the value in effect of a setting")
+                        .arg(config_key_arg()),
+                )
+                .subcommand(
+                    clap::Command::new(CMD_CONFIG_SET)
+                        .about("Store a setting, for the profile in use if it's a repo setting")
+                        .arg(config_key_arg())
+                        .arg(
+                            clap::Arg::new(ARG_VALUE)
+                                .required(true)
+                                .value_name("VALUE")
+                                .help("Value of the setting"),
+                        ),
+                )
+                .subcommand(
+                    clap::Command::new(CMD_CONFIG_UNSET)
+                        .about("Remove a stored setting")
+                        .arg(config_key_arg()),
+                )
+                .subcommand(
+                    clap::Command::new(CMD_CONFIG_LIST)
+                        .about("List the settings stored for the profile in use"),
+                )
+                .subcommand(
+                    clap::Command::new(CMD_CONFIG_EDIT)
+                        .about("Edit your stored configuration, it's checked before it's saved"),
+                )
+                .subcommand(
+                    clap::Command::new(CMD_CONFIG_PROFILES)
+                        .about("Manage your profiles, each with its own idea repo")
+                        .subcommand_required(true)
+                        .subcommand(
+                            clap::Command::new(CMD_PROFILES_LIST)
+                                .about("List your profiles, the default one marked with *"),
+                        )
+                        .subcommand(
+                            clap::Command::new(CMD_PROFILES_ADD)
+                                .about("Add a profile")
+                                .arg(profile_name_arg())
+                                .arg(
+                                    clap::Arg::new(ARG_REPO)
+                                        .required(true)
+                                        .value_name("REPO")
+                                        .help("Absolute path to the idea repo of the profile"),
+                                )
+                                .arg(
+                                    clap::Arg::new(ARG_BRANCH)
+                                        .long(ARG_BRANCH)
+                                        .value_name("BRANCH")
+                                        .help("Branch to commit to [default: main]"),
+                                )
+                                .arg(
+                                    clap::Arg::new(ARG_REMOTE)
+                                        .long(ARG_REMOTE)
+                                        .value_name("REMOTE")
+                                        .help("Remote to push to [default: origin]"),
+                                )
+                                .arg(
+                                    clap::Arg::new(ARG_STORAGE)
+                                        .long(ARG_STORAGE)
+                                        .value_name("LAYOUT")
+                                        .help("Storage layout, single-file or file-per-idea [default: single-file]"),
+                                )
+                                .arg(
+                                    clap::Arg::new(ARG_FILE)
+                                        .long(ARG_FILE)
+                                        .value_name("PATH")
+                                        .help("File in the repo to keep ideas in [default: README.md]"),
+                                )
+                                .arg(
+                                    clap::Arg::new(ARG_TEMPLATE)
+                                        .long(ARG_TEMPLATE)
+                                        .value_name("NAME")
+                                        .help("Template new ideas start from when no --template is given"),
+                                ),
+                        )
+                        .subcommand(
+                            clap::Command::new(CMD_PROFILES_RM)
+                                .about("Remove a profile, its idea repo is left as is")
+                                .arg(profile_name_arg()),
+                        )
+                        .subcommand(
+                            clap::Command::new(CMD_PROFILES_DEFAULT)
+                                .about("Use a profile when no --profile is given")
+                                .arg(profile_name_arg()),
+                        ),
+                ),
+        )
+        .subcommand(
+            clap::Command::new(CMD_LIST)
+                .about("List your ideas, newest first")
+                .arg(
+                    clap::Arg::new(ARG_SINCE)
+                        .long(ARG_SINCE)
+                        .value_name("DATE")
+                        .value_parser(parse_date)
+                        .help("Only list ideas created on or after DATE (YYYY-MM-DD)"),
+                )
+                .arg(
+                    clap::Arg::new(ARG_UNTIL)
+                        .long(ARG_UNTIL)
+                        .value_name("DATE")
+                        .value_parser(parse_date)
+                        .help("Only list ideas created on or before DATE (YYYY-MM-DD)"),
+                )
+                .arg(
+                    clap::Arg::new(ARG_TAG)
+                        .long(ARG_TAG)
+                        .short(ARG_TAG.chars().next().unwrap())
+                        .value_name("TAG")
+                        .action(ArgAction::Append)
+                        .help("Only list ideas with this tag, can be given more than once"),
+                )
+                .arg(status_arg("Only list ideas with this status"))
+                .arg(group_arg())
+                .arg(
+                    clap::Arg::new(ARG_LIMIT)
+                        .long(ARG_LIMIT)
+                        .short('n')
+                        .value_name("N")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("List at most N ideas"),
+                )
+                .arg(
+                    clap::Arg::new(ARG_SORT)
+                        .long(ARG_SORT)
+                        .value_name("FIELD")
+                        .value_parser(["date", "title"])
+                        .default_value("date")
+                        .help("Sort ideas by date or title"),
+                )
+                .arg(
+                    clap::Arg::new(ARG_REVERSE)
+                        .long(ARG_REVERSE)
+                        .short(ARG_REVERSE.chars().next().unwrap())
+                        .action(ArgAction::SetTrue)
+                        .help("Reverse the sort order"),
+                ),
+        )
+        .subcommand(
+            clap::Command::new(CMD_SEARCH)
+                .about("Search the titles and bodies of your ideas")
+                .arg(
+                    clap::Arg::new(ARG_QUERY)
+                        .required(true)
+                        .value_name("QUERY")
+                        .help("Text to search for"),
+                )
+                .arg(
+                    clap::Arg::new(ARG_IGNORE_CASE)
+                        .long(ARG_IGNORE_CASE)
+                        .short('i')
+                        .action(ArgAction::SetTrue)
+                        .help("Ignore case when matching"),
+                )
+                .arg(
+                    clap::Arg::new(ARG_REGEX)
+                        .long(ARG_REGEX)
+                        .short('E')
+                        .action(ArgAction::SetTrue)
+                        .help("Treat QUERY as a regular expression"),
+                )
+                .arg(
+                    clap::Arg::new(ARG_COMMITS)
+                        .long(ARG_COMMITS)
+                        .short(ARG_COMMITS.chars().next().unwrap())
+                        .action(ArgAction::SetTrue)
+                        .help("Also search the commit messages of your idea repo"),
+                ),
+        )
+        .subcommand(
+            clap::Command::new(CMD_TAGS).about("List every tag with the number of ideas using it"),
+        )
+        .subcommand(
+            clap::Command::new(CMD_EDIT)
+                .about("Edit an idea with your $EDITOR env variable")
+                .arg(idea_arg()),
+        )
+        .subcommand(
+            clap::Command::new(CMD_STATUS)
+                .about("Move an idea to another status")
+                .arg(idea_arg())
+                .arg(
+                    clap::Arg::new(ARG_NEW_STATUS)
+                        .required(true)
+                        .value_name("STATUS")
+                        .value_parser(parse_status)
+                        .help("One of new, exploring, doing, done or dropped"),
+                ),
+        )
+        .subcommand(
+            clap::Command::new(CMD_TAG)
+                .about("Replace the tags of an idea, leave them out to remove them all")
+                .arg(idea_arg())
+                .arg(
+                    clap::Arg::new(ARG_NEW_TAGS)
+                        .num_args(0..)
+                        .value_name("TAGS")
+                        .help("Tags with or without the leading #"),
+                ),
+        )
+        .subcommand(
+            clap::Command::new(CMD_RM)
+                .about("Delete an idea")
+                .arg(idea_arg()),
+        )
+        .subcommand(
+            clap::Command::new(CMD_ARCHIVE)
+                .about("Move an idea to the archive")
+                .arg(idea_arg()),
+        )
+        .subcommand(
+            clap::Command::new(CMD_BROWSE)
+                .about("Browse, filter and change your ideas in a full screen view"),
+        )
+        .subcommand(clap::Command::new(CMD_UNDO).about("Revert the last change eureka committed"))
+        .subcommand(
+            clap::Command::new(CMD_SYNC).about("Push ideas that were committed while offline"),
+        )
+        .subcommand(
+            clap::Command::new(CMD_DOCTOR)
+                .about("Check that your config and idea repo are set up correctly"),
         )
         .get_matches();
 
+    let command = match to_command(&cli_flags) {
+        Ok(command) => command,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+
     let stdio = io::stdin();
     let input = stdio.lock();
     let output = termcolor::StandardStream::stdout(termcolor::ColorChoice::Always);
 
+    let cm = match to_config_manager(&cli_flags) {
+        Ok(cm) => cm,
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    };
+    // Upgrade an older config once, up front. When that fails the command
+    // reports why as soon as it reads the config, if it reads it at all
+    if let Err(e) = cm.migrate_config() {
+        debug!("Could not migrate the config: {}", e);
+    }
+    // The config wins over the environment
+    let program_access = ProgramAccess::default()
+        .with_editor(cm.config_read(ConfigType::Editor).ok())
+        .with_pager(cm.config_read(ConfigType::Pager).ok());
+
     let mut eureka = Eureka::new(
-        ConfigManager::default(),
+        cm,
         Printer::new(output),
         Reader::new(input),
         Git::default(),
-        ProgramAccess::default(),
+        program_access,
     );
 
-    let opts = EurekaOptions {
-        clear_config: cli_flags.get_flag(ARG_CLEAR_CONFIG),
-        view: cli_flags.get_flag(ARG_VIEW),
+    match eureka.run(command) {
+        Ok(_) => {}
+        Err(e) => {
+            error!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// The command line wins over the nearest .eureka.toml, which wins over config.json
+fn to_config_manager(cli_flags: &ArgMatches) -> io::Result<ConfigManager> {
+    ConfigManager::default()
+        .with_profile(cli_flags.get_one::<String>(ARG_PROFILE).cloned())
+        .with_override(
+            ConfigType::Editor,
+            cli_flags.get_one::<String>(ARG_EDITOR).cloned(),
+        )
+        .with_override(
+            ConfigType::Pager,
+            cli_flags.get_one::<String>(ARG_PAGER).cloned(),
+        )
+        .with_local_config(&env::current_dir()?)
+}
+
+fn add_args() -> [clap::Arg; 4] {
+    [
+        clap::Arg::new(ARG_MESSAGE)
+            .long(ARG_MESSAGE)
+            .short(ARG_MESSAGE.chars().next().unwrap())
+            .value_name("SUMMARY")
+            .help("Idea summary, store the idea without prompting or opening $EDITOR"),
+        clap::Arg::new(ARG_BODY_FILE)
+            .long(ARG_BODY_FILE)
+            .value_name("PATH")
+            .help("Read the idea body from a file, use - to read from stdin"),
+        clap::Arg::new(ARG_OFFLINE)
+            .long(ARG_OFFLINE)
+            .action(ArgAction::SetTrue)
+            .help("Only commit the idea locally, push it later with `sync`"),
+        clap::Arg::new(ARG_TEMPLATE)
+            .long(ARG_TEMPLATE)
+            .value_name("NAME")
+            .help("Start the idea from the template NAME.md in .eureka/templates or your config directory"),
+    ]
+}
+
+fn idea_arg() -> clap::Arg {
+    clap::Arg::new(ARG_IDEA)
+        .required(true)
+        .value_name("ID|QUERY")
+        .help("Id of the idea as shown by `list`, or part of its title")
+}
+
+fn profile_name_arg() -> clap::Arg {
+    clap::Arg::new(ARG_NAME)
+        .required(true)
+        .value_name("NAME")
+        .help("Name of the profile")
+}
+
+fn status_arg(help: &'static str) -> clap::Arg {
+    clap::Arg::new(ARG_STATUS)
+        .long(ARG_STATUS)
+        .short(ARG_STATUS.chars().next().unwrap())
+        .value_name("STATUS")
+        .value_parser(parse_status)
+        .help(help)
+}
+
+fn config_key_arg() -> clap::Arg {
+    let keys: Vec<&str> = ConfigType::ALL.iter().map(ConfigType::key).collect();
+    clap::Arg::new(ARG_KEY)
+        .required(true)
+        .value_name("KEY")
+        .value_parser(parse_config_type)
+        .help(format!("Name of the setting: {}", keys.join(", ")))
+}
+
+fn group_arg() -> clap::Arg {
+    clap::Arg::new(ARG_GROUP)
+        .long(ARG_GROUP)
+        .short(ARG_GROUP.chars().next().unwrap())
+        .action(ArgAction::SetTrue)
+        .help("Group ideas by status")
+}
+
+fn to_command(cli_flags: &ArgMatches) -> io::Result<EurekaCommand> {
+    let command = match cli_flags.subcommand() {
+        Some((CMD_ADD, add_flags)) => EurekaCommand::Add(to_add_options(add_flags)?),
+        Some((CMD_VIEW, view_flags)) => EurekaCommand::View(ViewOptions {
+            status: view_flags.get_one::<IdeaStatus>(ARG_STATUS).copied(),
+            group: view_flags.get_flag(ARG_GROUP),
+            raw: view_flags.get_flag(ARG_RAW),
+        }),
+        Some((CMD_CONFIG, config_flags)) => match config_flags.subcommand() {
+            Some((CMD_CONFIG_CLEAR, _)) => EurekaCommand::Config(ConfigCommand::Clear),
+            Some((CMD_CONFIG_SHOW, show_flags)) => EurekaCommand::Config(ConfigCommand::Show {
+                origin: show_flags.get_flag(ARG_ORIGIN),
+            }),
+            Some((CMD_CONFIG_GET, get_flags)) => {
+                EurekaCommand::Config(ConfigCommand::Get(config_key(get_flags)))
+            }
+            Some((CMD_CONFIG_SET, set_flags)) => EurekaCommand::Config(ConfigCommand::Set(
+                config_key(set_flags),
+                set_flags
+                    .get_one::<String>(ARG_VALUE)
+                    .cloned()
+                    .unwrap_or_default(),
+            )),
+            Some((CMD_CONFIG_UNSET, unset_flags)) => {
+                EurekaCommand::Config(ConfigCommand::Unset(config_key(unset_flags)))
+            }
+            Some((CMD_CONFIG_LIST, _)) => EurekaCommand::Config(ConfigCommand::List),
+            Some((CMD_CONFIG_EDIT, _)) => EurekaCommand::Config(ConfigCommand::Edit),
+            Some((CMD_CONFIG_PROFILES, profiles_flags)) => {
+                EurekaCommand::Config(ConfigCommand::Profiles(to_profile_command(profiles_flags)))
+            }
+            _ => unreachable!("config subcommand is required"),
+        },
+        Some((CMD_TAGS, _)) => EurekaCommand::Tags,
+        Some((CMD_EDIT, edit_flags)) => EurekaCommand::Edit(to_idea_target(edit_flags)),
+        Some((CMD_STATUS, status_flags)) => EurekaCommand::Status(
+            to_idea_target(status_flags),
+            status_flags
+                .get_one::<IdeaStatus>(ARG_NEW_STATUS)
+                .copied()
+                .unwrap_or_default(),
+        ),
+        Some((CMD_TAG, tag_flags)) => EurekaCommand::Tag(
+            to_idea_target(tag_flags),
+            tag_flags
+                .get_many::<String>(ARG_NEW_TAGS)
+                .unwrap_or_default()
+                .cloned()
+                .collect(),
+        ),
+        Some((CMD_RM, rm_flags)) => EurekaCommand::Remove(to_idea_target(rm_flags)),
+        Some((CMD_ARCHIVE, archive_flags)) => EurekaCommand::Archive(to_idea_target(archive_flags)),
+        Some((CMD_BROWSE, _)) => EurekaCommand::Browse,
+        Some((CMD_UNDO, _)) => EurekaCommand::Undo,
+        Some((CMD_SYNC, _)) => EurekaCommand::Sync,
+        Some((CMD_LIST, list_flags)) => EurekaCommand::List(to_list_options(list_flags)),
+        Some((CMD_SEARCH, search_flags)) => EurekaCommand::Search(SearchOptions {
+            query: search_flags
+                .get_one::<String>(ARG_QUERY)
+                .cloned()
+                .unwrap_or_default(),
+            ignore_case: search_flags.get_flag(ARG_IGNORE_CASE),
+            regex: search_flags.get_flag(ARG_REGEX),
+            commits: search_flags.get_flag(ARG_COMMITS),
+        }),
+        Some((CMD_DOCTOR, _)) => EurekaCommand::Doctor,
+        _ if cli_flags.get_flag(ARG_CLEAR_CONFIG) => EurekaCommand::Config(ConfigCommand::Clear),
+        _ if cli_flags.get_flag(ARG_VIEW) => EurekaCommand::View(ViewOptions::default()),
+        _ => EurekaCommand::Add(to_add_options(cli_flags)?),
     };
+    Ok(command)
+}
 
-    match eureka.run(opts) {
-        Ok(_) => {}
-        Err(e) => error!("{}", e),
+fn to_profile_command(profiles_flags: &ArgMatches) -> ProfileCommand {
+    let name = |flags: &ArgMatches| {
+        flags
+            .get_one::<String>(ARG_NAME)
+            .cloned()
+            .unwrap_or_default()
+    };
+    match profiles_flags.subcommand() {
+        Some((CMD_PROFILES_LIST, _)) => ProfileCommand::List,
+        Some((CMD_PROFILES_ADD, add_flags)) => ProfileCommand::Add(
+            name(add_flags),
+            Profile {
+                repo: add_flags.get_one::<String>(ARG_REPO).map(PathBuf::from),
+                branch: add_flags.get_one::<String>(ARG_BRANCH).cloned(),
+                remote: add_flags.get_one::<String>(ARG_REMOTE).cloned(),
+                storage: add_flags.get_one::<String>(ARG_STORAGE).cloned(),
+                template: add_flags.get_one::<String>(ARG_TEMPLATE).cloned(),
+                file: add_flags.get_one::<String>(ARG_FILE).cloned(),
+                ..Profile::default()
+            },
+        ),
+        Some((CMD_PROFILES_RM, rm_flags)) => ProfileCommand::Remove(name(rm_flags)),
+        Some((CMD_PROFILES_DEFAULT, default_flags)) => ProfileCommand::Default(name(default_flags)),
+        _ => unreachable!("profiles subcommand is required"),
+    }
+}
+
+fn to_add_options(add_flags: &ArgMatches) -> io::Result<AddOptions> {
+    let body = match add_flags.get_one::<String>(ARG_BODY_FILE) {
+        Some(path) => Some(read_body(path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Could not read idea body from {}: {}", path, e),
+            )
+        })?),
+        None => None,
+    };
+
+    Ok(AddOptions {
+        message: add_flags.get_one::<String>(ARG_MESSAGE).cloned(),
+        body,
+        offline: add_flags.get_flag(ARG_OFFLINE),
+        template: add_flags.get_one::<String>(ARG_TEMPLATE).cloned(),
+    })
+}
+
+fn to_idea_target(idea_flags: &ArgMatches) -> String {
+    idea_flags
+        .get_one::<String>(ARG_IDEA)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn config_key(config_flags: &ArgMatches) -> ConfigType {
+    *config_flags
+        .get_one::<ConfigType>(ARG_KEY)
+        .expect("key is required")
+}
+
+fn to_list_options(list_flags: &ArgMatches) -> ListOptions {
+    ListOptions {
+        since: list_flags.get_one::<NaiveDate>(ARG_SINCE).copied(),
+        until: list_flags.get_one::<NaiveDate>(ARG_UNTIL).copied(),
+        tags: list_flags
+            .get_many::<String>(ARG_TAG)
+            .unwrap_or_default()
+            .cloned()
+            .collect(),
+        status: list_flags.get_one::<IdeaStatus>(ARG_STATUS).copied(),
+        limit: list_flags.get_one::<usize>(ARG_LIMIT).copied(),
+        sort: match list_flags.get_one::<String>(ARG_SORT).map(String::as_str) {
+            Some("title") => SortBy::Title,
+            _ => SortBy::Date,
+        },
+        reverse: list_flags.get_flag(ARG_REVERSE),
+        group: list_flags.get_flag(ARG_GROUP),
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("{} is not a date, use YYYY-MM-DD", value))
+}
+
+fn parse_status(value: &str) -> Result<IdeaStatus, String> {
+    value.parse::<IdeaStatus>().map_err(|err| err.to_string())
+}
+
+fn parse_config_type(value: &str) -> Result<ConfigType, String> {
+    value.parse::<ConfigType>().map_err(|err| err.to_string())
+}
+
+fn read_body(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut body = String::new();
+        io::stdin().read_to_string(&mut body)?;
+        Ok(body)
+    } else {
+        fs::read_to_string(path)
     }
 }
@@ -0,0 +1,576 @@
+use std::cmp::Reverse;
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::config_manager::ConfigManagement;
+use crate::git::GitManagement;
+use crate::idea::{Idea, IdeaStatus};
+use crate::printer::{Print, PrintColor};
+use crate::program_access::ProgramOpener;
+use crate::reader::ReadInput;
+use crate::{Eureka, EurekaCommand};
+
+const HELP: &str = "enter edit · ^s status · ^t tags · ^a archive · ^d delete · esc clear/quit";
+
+/// Something to do with an idea, picked in the browser
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    Edit(String),
+    SetStatus(String, IdeaStatus),
+    SetTags(String, Vec<String>),
+    Archive(String),
+    Remove(String),
+}
+
+impl Action {
+    fn command(&self) -> EurekaCommand {
+        match self {
+            Action::Edit(id) => EurekaCommand::Edit(id.clone()),
+            Action::SetStatus(id, status) => EurekaCommand::Status(id.clone(), *status),
+            Action::SetTags(id, tags) => EurekaCommand::Tag(id.clone(), tags.clone()),
+            Action::Archive(id) => EurekaCommand::Archive(id.clone()),
+            Action::Remove(id) => EurekaCommand::Remove(id.clone()),
+        }
+    }
+}
+
+/// What the browser should do after a key press
+#[derive(Debug, PartialEq)]
+pub enum Step {
+    Continue,
+    Act(Action),
+    Quit,
+}
+
+#[derive(Debug, PartialEq)]
+enum Mode {
+    Filter,
+    // Index into `IdeaStatus::ALL`
+    PickStatus(usize),
+    // Tags as typed so far
+    EditTags(String),
+    ConfirmRemove,
+}
+
+/// Everything the browser shows, kept apart from the terminal so key
+/// handling can be tested
+pub struct BrowseState {
+    // Newest first
+    ideas: Vec<Idea>,
+    query: String,
+    // Indices into `ideas` that match the query, best match first
+    matches: Vec<usize>,
+    selected: usize,
+    mode: Mode,
+    message: Option<String>,
+}
+
+impl BrowseState {
+    pub fn new(ideas: Vec<Idea>) -> Self {
+        let mut state = BrowseState {
+            ideas: vec![],
+            query: String::new(),
+            matches: vec![],
+            selected: 0,
+            mode: Mode::Filter,
+            message: None,
+        };
+        state.set_ideas(ideas);
+        state
+    }
+
+    /// Swap in freshly read ideas, keeping the query and the selection
+    pub fn set_ideas(&mut self, mut ideas: Vec<Idea>) {
+        ideas.sort_by_key(|idea| Reverse(idea.date()));
+        self.ideas = ideas;
+        self.update_matches();
+        self.selected = self.selected.min(self.matches.len().saturating_sub(1));
+    }
+
+    pub fn selected_idea(&self) -> Option<&Idea> {
+        self.matches
+            .get(self.selected)
+            .map(|index| &self.ideas[*index])
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Step {
+        self.message = None;
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        if ctrl && key.code == KeyCode::Char('c') {
+            return Step::Quit;
+        }
+
+        match &mut self.mode {
+            Mode::Filter => self.handle_filter_key(key.code, ctrl),
+            Mode::PickStatus(index) => match key.code {
+                KeyCode::Up => {
+                    *index = index.saturating_sub(1);
+                    Step::Continue
+                }
+                KeyCode::Down => {
+                    *index = (*index + 1).min(IdeaStatus::ALL.len() - 1);
+                    Step::Continue
+                }
+                KeyCode::Enter => {
+                    let status = IdeaStatus::ALL[*index];
+                    self.mode = Mode::Filter;
+                    self.act(|id| Action::SetStatus(id, status))
+                }
+                KeyCode::Esc => {
+                    self.mode = Mode::Filter;
+                    Step::Continue
+                }
+                _ => Step::Continue,
+            },
+            Mode::EditTags(input) => match key.code {
+                KeyCode::Char(c) if !ctrl => {
+                    input.push(c);
+                    Step::Continue
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                    Step::Continue
+                }
+                KeyCode::Enter => {
+                    let tags = input
+                        .split_whitespace()
+                        .map(|tag| tag.trim_start_matches('#').to_string())
+                        .filter(|tag| !tag.is_empty())
+                        .collect::<Vec<String>>();
+                    self.mode = Mode::Filter;
+                    self.act(|id| Action::SetTags(id, tags))
+                }
+                KeyCode::Esc => {
+                    self.mode = Mode::Filter;
+                    Step::Continue
+                }
+                _ => Step::Continue,
+            },
+            Mode::ConfirmRemove => {
+                self.mode = Mode::Filter;
+                match key.code {
+                    KeyCode::Char('y') => self.act(Action::Remove),
+                    _ => Step::Continue,
+                }
+            }
+        }
+    }
+
+    fn handle_filter_key(&mut self, code: KeyCode, ctrl: bool) -> Step {
+        match code {
+            KeyCode::Char('s') if ctrl => {
+                if let Some(idea) = self.selected_idea() {
+                    let index = IdeaStatus::ALL
+                        .iter()
+                        .position(|status| status.to_string() == idea.status())
+                        .unwrap_or_default();
+                    self.mode = Mode::PickStatus(index);
+                }
+                Step::Continue
+            }
+            KeyCode::Char('t') if ctrl => {
+                if let Some(idea) = self.selected_idea() {
+                    let tags = idea
+                        .tags
+                        .iter()
+                        .map(|tag| format!("#{} ", tag))
+                        .collect::<String>();
+                    self.mode = Mode::EditTags(tags);
+                }
+                Step::Continue
+            }
+            KeyCode::Char('d') if ctrl => {
+                if self.selected_idea().is_some() {
+                    self.mode = Mode::ConfirmRemove;
+                }
+                Step::Continue
+            }
+            KeyCode::Char('a') if ctrl => self.act(Action::Archive),
+            KeyCode::Char('p') if ctrl => self.move_selection(-1),
+            KeyCode::Char('n') if ctrl => self.move_selection(1),
+            KeyCode::Char(c) if !ctrl => {
+                self.query.push(c);
+                self.update_matches();
+                self.selected = 0;
+                Step::Continue
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.update_matches();
+                self.selected = 0;
+                Step::Continue
+            }
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::Enter => self.act(Action::Edit),
+            KeyCode::Esc if self.query.is_empty() => Step::Quit,
+            KeyCode::Esc => {
+                self.query.clear();
+                self.update_matches();
+                self.selected = 0;
+                Step::Continue
+            }
+            _ => Step::Continue,
+        }
+    }
+
+    fn act(&self, action: impl FnOnce(String) -> Action) -> Step {
+        match self.selected_idea() {
+            Some(idea) => Step::Act(action(idea.id.clone())),
+            None => Step::Continue,
+        }
+    }
+
+    fn move_selection(&mut self, by: isize) -> Step {
+        let last = self.matches.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(by).min(last);
+        Step::Continue
+    }
+
+    fn update_matches(&mut self) {
+        let mut scored = self
+            .ideas
+            .iter()
+            .enumerate()
+            .filter_map(|(index, idea)| {
+                let text = format!("{} #{}", idea.title, idea.tags.join(" #"));
+                fuzzy_score(&self.query, &text).map(|score| (index, score))
+            })
+            .collect::<Vec<(usize, i64)>>();
+        // Stable, so equally good matches stay newest first
+        scored.sort_by_key(|(_, score)| Reverse(*score));
+        self.matches = scored.into_iter().map(|(index, _)| index).collect();
+    }
+}
+
+/// How well `query` matches `text`, if every character of the query is found
+/// in order. Runs of characters and matches at word starts score higher.
+fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text = text.to_lowercase().chars().collect::<Vec<char>>();
+    let mut score = 0;
+    let mut from = 0;
+    let mut last: Option<usize> = None;
+    for wanted in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = from + text[from..].iter().position(|c| *c == wanted)?;
+        score += 1;
+        match last {
+            Some(last) if last + 1 == found => score += 5,
+            Some(last) => score -= (found - last - 1).min(5) as i64,
+            None => {}
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        last = Some(found);
+        from = found + 1;
+    }
+    Some(score)
+}
+
+/// Show the browser until the user quits. Actions leave the full screen UI
+/// while they run, so the editor and git prompts get the terminal.
+pub fn run<CM, W, R, G, PO>(eureka: &mut Eureka<CM, W, R, G, PO>) -> io::Result<()>
+where
+    CM: ConfigManagement,
+    W: Print + PrintColor,
+    R: ReadInput,
+    G: GitManagement,
+    PO: ProgramOpener,
+{
+    let mut state = BrowseState::new(eureka.ideas()?);
+    let mut terminal = ratatui::try_init()?;
+    let result = browse(&mut terminal, eureka, &mut state);
+    ratatui::try_restore()?;
+    result
+}
+
+fn browse<CM, W, R, G, PO>(
+    terminal: &mut DefaultTerminal,
+    eureka: &mut Eureka<CM, W, R, G, PO>,
+    state: &mut BrowseState,
+) -> io::Result<()>
+where
+    CM: ConfigManagement,
+    W: Print + PrintColor,
+    R: ReadInput,
+    G: GitManagement,
+    PO: ProgramOpener,
+{
+    loop {
+        terminal.draw(|frame| draw(frame, state))?;
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+
+        let action = match state.handle_key(key) {
+            Step::Continue => continue,
+            Step::Quit => return Ok(()),
+            Step::Act(action) => action,
+        };
+
+        ratatui::try_restore()?;
+        let result = eureka.run(action.command());
+        *terminal = ratatui::try_init()?;
+        terminal.clear()?;
+
+        state.set_ideas(eureka.ideas()?);
+        state.message = Some(match result {
+            Ok(()) => "Done".to_string(),
+            Err(err) => err.to_string(),
+        });
+    }
+}
+
+fn draw(frame: &mut Frame, state: &BrowseState) {
+    let [query_area, main_area, footer_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [list_area, preview_area] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .areas(main_area);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("> ", Style::new().fg(Color::Cyan)),
+            Span::raw(state.query.as_str()),
+            Span::styled(
+                format!("  {}/{}", state.matches.len(), state.ideas.len()),
+                Style::new().add_modifier(Modifier::DIM),
+            ),
+        ])),
+        query_area,
+    );
+
+    let items = state
+        .matches
+        .iter()
+        .map(|index| list_item(&state.ideas[*index]))
+        .collect::<Vec<ListItem>>();
+    let list = List::new(items)
+        .block(Block::new().borders(Borders::RIGHT))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    let mut list_state = ListState::default().with_selected(Some(state.selected));
+    frame.render_stateful_widget(list, list_area, &mut list_state);
+
+    if let Some(idea) = state.selected_idea() {
+        frame.render_widget(
+            Paragraph::new(preview(idea)).wrap(Wrap { trim: false }),
+            preview_area.inner(ratatui::layout::Margin::new(1, 0)),
+        );
+    }
+
+    let footer = match &state.mode {
+        Mode::Filter => match &state.message {
+            Some(message) => Line::from(message.as_str()),
+            None => Line::styled(HELP, Style::new().add_modifier(Modifier::DIM)),
+        },
+        Mode::PickStatus(_) => Line::from("Pick a status, enter to set, esc to cancel"),
+        Mode::EditTags(input) => Line::from(vec![
+            Span::styled("Tags: ", Style::new().fg(Color::Cyan)),
+            Span::raw(input.as_str()),
+        ]),
+        Mode::ConfirmRemove => Line::from("Delete this idea? y/n"),
+    };
+    frame.render_widget(Paragraph::new(footer), footer_area);
+
+    if let Mode::PickStatus(index) = state.mode {
+        let area = centered(preview_area, 20, IdeaStatus::ALL.len() as u16 + 2);
+        let statuses = List::new(
+            IdeaStatus::ALL
+                .iter()
+                .map(|status| ListItem::new(status.to_string())),
+        )
+        .block(Block::bordered().title("Status"))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(
+            statuses,
+            area,
+            &mut ListState::default().with_selected(Some(index)),
+        );
+    }
+}
+
+fn list_item(idea: &Idea) -> ListItem<'_> {
+    let mut spans = vec![
+        Span::styled(
+            format!("{:<10} ", idea.status()),
+            Style::new().fg(status_color(idea.status())),
+        ),
+        Span::raw(idea.title.as_str()),
+    ];
+    for tag in &idea.tags {
+        spans.push(Span::styled(
+            format!(" #{}", tag),
+            Style::new().fg(Color::Blue),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
+
+fn preview(idea: &Idea) -> Vec<Line<'_>> {
+    let mut lines = vec![Line::styled(
+        idea.title.as_str(),
+        Style::new().add_modifier(Modifier::BOLD),
+    )];
+    let mut meta = vec![Span::styled(
+        idea.status().to_string(),
+        Style::new().fg(status_color(idea.status())),
+    )];
+    if let Some(date) = idea.date() {
+        meta.push(Span::raw(format!(" · {}", date.format("%Y-%m-%d"))));
+    }
+    if let Some(author) = &idea.author {
+        meta.push(Span::raw(format!(" · {}", author)));
+    }
+    for tag in &idea.tags {
+        meta.push(Span::styled(
+            format!(" #{}", tag),
+            Style::new().fg(Color::Blue),
+        ));
+    }
+    lines.push(Line::from(meta).style(Style::new().add_modifier(Modifier::DIM)));
+    lines.push(Line::default());
+    lines.extend(idea.body.lines().map(Line::raw));
+    lines
+}
+
+fn status_color(status: &str) -> Color {
+    match status {
+        "exploring" => Color::Yellow,
+        "doing" => Color::Cyan,
+        "done" => Color::Green,
+        "dropped" => Color::DarkGray,
+        _ => Color::Magenta,
+    }
+}
+
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use crate::browse::{fuzzy_score, Action, BrowseState, Mode, Step};
+    use crate::idea::{Idea, IdeaFormat, IdeaStatus};
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::path::Path;
+
+    fn idea(id: &str, title: &str, tags: &[&str]) -> Idea {
+        let mut idea = Idea::new(IdeaFormat::Section, Path::new("README.md"), title);
+        idea.id = id.to_string();
+        idea.tags = tags.iter().map(|tag| tag.to_string()).collect();
+        idea
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn typed(state: &mut BrowseState, text: &str) {
+        for c in text.chars() {
+            state.handle_key(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_browse__fuzzy_score__prefers_runs_and_word_starts() {
+        assert_eq!(fuzzy_score("xyz", "some idea"), None);
+        assert_eq!(fuzzy_score("", "some idea"), Some(0));
+
+        let run = fuzzy_score("idea", "some idea").unwrap();
+        let scattered = fuzzy_score("idea", "i did eat a lot").unwrap();
+        assert!(run > scattered);
+
+        let word_start = fuzzy_score("cli", "rust cli").unwrap();
+        let inside = fuzzy_score("cli", "recliner").unwrap();
+        assert!(word_start > inside);
+    }
+
+    #[test]
+    fn test_browse__handle_key__filters_as_you_type() {
+        let mut state = BrowseState::new(vec![
+            idea("1a2b3c4", "Garden planner", &[]),
+            idea("5d6e7f8", "Rust CLI", &["tools"]),
+        ]);
+        assert_eq!(state.matches.len(), 2);
+
+        typed(&mut state, "tools");
+        assert_eq!(state.matches.len(), 1);
+        assert_eq!(state.selected_idea().unwrap().id, "5d6e7f8");
+        assert_eq!(
+            state.handle_key(key(KeyCode::Enter)),
+            Step::Act(Action::Edit("5d6e7f8".to_string()))
+        );
+
+        assert_eq!(state.handle_key(key(KeyCode::Esc)), Step::Continue);
+        assert_eq!(state.matches.len(), 2);
+        assert_eq!(state.handle_key(key(KeyCode::Esc)), Step::Quit);
+    }
+
+    #[test]
+    fn test_browse__handle_key__picks_status_and_tags() {
+        let mut state = BrowseState::new(vec![idea("1a2b3c4", "Rust CLI", &["tools"])]);
+
+        state.handle_key(ctrl('s'));
+        assert_eq!(state.mode, Mode::PickStatus(0));
+        state.handle_key(key(KeyCode::Down));
+        state.handle_key(key(KeyCode::Down));
+        assert_eq!(
+            state.handle_key(key(KeyCode::Enter)),
+            Step::Act(Action::SetStatus("1a2b3c4".to_string(), IdeaStatus::Doing))
+        );
+
+        state.handle_key(ctrl('t'));
+        assert_eq!(state.mode, Mode::EditTags("#tools ".to_string()));
+        typed(&mut state, "#rust");
+        assert_eq!(
+            state.handle_key(key(KeyCode::Enter)),
+            Step::Act(Action::SetTags(
+                "1a2b3c4".to_string(),
+                vec!["tools".to_string(), "rust".to_string()]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_browse__handle_key__asks_before_removing() {
+        let mut state = BrowseState::new(vec![idea("1a2b3c4", "Rust CLI", &[])]);
+
+        state.handle_key(ctrl('d'));
+        assert_eq!(state.handle_key(key(KeyCode::Char('n'))), Step::Continue);
+        assert_eq!(state.mode, Mode::Filter);
+
+        state.handle_key(ctrl('d'));
+        assert_eq!(
+            state.handle_key(key(KeyCode::Char('y'))),
+            Step::Act(Action::Remove("1a2b3c4".to_string()))
+        );
+        assert_eq!(
+            state.handle_key(ctrl('a')),
+            Step::Act(Action::Archive("1a2b3c4".to_string()))
+        );
+    }
+}
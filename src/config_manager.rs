@@ -1,34 +1,317 @@
 use crate::dirs::home_dir;
+use crate::program_access::find_program;
+use crate::storage::{IdeaPosition, StorageLayout};
+use crate::template::{template_path, TEMPLATES_DIR_NAME};
 
+use std::collections::BTreeMap;
 use std::env::var;
-use std::io::{ErrorKind, Read, Write};
-use std::path::PathBuf;
+use std::fmt;
+use std::io::{ErrorKind, Write};
+use std::iter;
+use std::mem;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
 use std::{fs, io};
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 const CONFIG_FILE_NAME: &str = "config.json";
+// Where eureka 1.x kept its settings, relative to $HOME
+const LEGACY_CONFIG_DIR_NAME: &str = ".eureka";
+const CONFIG_TMP_FILE_NAME: &str = "config.json.tmp";
+const PENDING_PUSH_FILE_NAME: &str = "pending-push";
+pub const LOCAL_CONFIG_FILE_NAME: &str = ".eureka.toml";
+// The profile stored at the top level of the config
+pub const DEFAULT_PROFILE: &str = "default";
+
+// Bump this, and add a step to MIGRATIONS, when the format of config.json changes.
+// Configs without a version, from 2.0 or imported from 1.x, are version 1 as they are
+const CONFIG_VERSION: u64 = 1;
+// MIGRATIONS[n] upgrades a config from version n + 1 to n + 2
+const MIGRATIONS: [fn(&mut Map<String, Value>); CONFIG_VERSION as usize - 1] = [];
 
 #[derive(Serialize, Deserialize, Default)]
 struct Config {
-    repo: PathBuf,
+    // Missing in configs written before versioning, 2.0 and earlier
+    #[serde(default)]
+    version: u64,
+    #[serde(flatten)]
+    profile: Profile,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    position: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    editor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pager: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default_profile: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    profiles: BTreeMap<String, Profile>,
 }
 
-#[derive(Debug, Eq, PartialEq)]
+impl Config {
+    fn new() -> Self {
+        Config {
+            version: CONFIG_VERSION,
+            ..Config::default()
+        }
+    }
+
+    /// The value stored for profile `name`, without falling back on anything
+    fn value(&self, name: &str, config_type: ConfigType) -> Option<String> {
+        let profile = if name == DEFAULT_PROFILE {
+            &self.profile
+        } else {
+            self.profiles.get(name)?
+        };
+        match config_type {
+            ConfigType::Repo => profile.repo.as_ref().map(|repo| repo.display().to_string()),
+            ConfigType::Branch => profile.branch.clone(),
+            ConfigType::Remote => profile.remote.clone(),
+            ConfigType::Storage => profile.storage.clone(),
+            ConfigType::Template => profile.template.clone(),
+            ConfigType::File => profile.file.clone(),
+            ConfigType::Position => self.position.clone(),
+            ConfigType::Editor => self.editor.clone(),
+            ConfigType::Pager => self.pager.clone(),
+        }
+    }
+}
+
+/// An idea repo and how ideas are stored in it
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct Profile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Settings this version doesn't know about, written back as they are.
+    /// For the default profile this holds the unknown settings of the whole config
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Settings for one project, from a `.eureka.toml` in the current directory
+/// or one of its parents
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct LocalConfig {
+    #[serde(skip)]
+    path: PathBuf,
+    profile: Option<String>,
+    repo: Option<PathBuf>,
+    file: Option<String>,
+    branch: Option<String>,
+    remote: Option<String>,
+    storage: Option<String>,
+    template: Option<String>,
+    position: Option<String>,
+    editor: Option<String>,
+    pager: Option<String>,
+}
+
+impl LocalConfig {
+    fn value(&self, config_type: ConfigType) -> Option<String> {
+        match config_type {
+            // Relative to the directory the file is in, so `repo = "."` is the project itself
+            ConfigType::Repo => self.repo.as_ref().map(|repo| {
+                let dir = self.path.parent().unwrap_or(Path::new(""));
+                dir.join(repo)
+                    .components()
+                    .collect::<PathBuf>()
+                    .display()
+                    .to_string()
+            }),
+            ConfigType::File => self.file.clone(),
+            ConfigType::Branch => self.branch.clone(),
+            ConfigType::Remote => self.remote.clone(),
+            ConfigType::Storage => self.storage.clone(),
+            ConfigType::Template => self.template.clone(),
+            ConfigType::Position => self.position.clone(),
+            ConfigType::Editor => self.editor.clone(),
+            ConfigType::Pager => self.pager.clone(),
+        }
+    }
+}
+
+/// Where a config value comes from, from most to least important
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigOrigin {
+    CommandLine,
+    // A `.eureka.toml` in the current directory or one of its parents
+    Local(PathBuf),
+    // A profile other than the default one in config.json
+    Profile(String),
+    // The top level of config.json
+    Global(PathBuf),
+}
+
+impl fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigOrigin::CommandLine => write!(f, "command line"),
+            ConfigOrigin::Local(path) | ConfigOrigin::Global(path) => {
+                write!(f, "{}", path.display())
+            }
+            ConfigOrigin::Profile(name) => write!(f, "profile {}", name),
+        }
+    }
+}
+
+/// Every profile by name, along with the one used when none is picked
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Profiles {
+    pub default: String,
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ConfigType {
     Repo,
+    Branch,
+    Remote,
+    Storage,
+    Position,
+    Editor,
+    Pager,
+    Template,
+    File,
+}
+
+impl ConfigType {
+    pub const ALL: [ConfigType; 9] = [
+        ConfigType::Repo,
+        ConfigType::File,
+        ConfigType::Branch,
+        ConfigType::Remote,
+        ConfigType::Storage,
+        ConfigType::Template,
+        ConfigType::Position,
+        ConfigType::Editor,
+        ConfigType::Pager,
+    ];
+
+    /// Name of the setting in the config files
+    pub fn key(&self) -> &'static str {
+        match self {
+            ConfigType::Repo => "repo",
+            ConfigType::Branch => "branch",
+            ConfigType::Remote => "remote",
+            ConfigType::Storage => "storage",
+            ConfigType::Position => "position",
+            ConfigType::Editor => "editor",
+            ConfigType::Pager => "pager",
+            ConfigType::Template => "template",
+            ConfigType::File => "file",
+        }
+    }
+
+    /// Check that `value` makes sense for this setting
+    pub fn validate(&self, value: &str) -> io::Result<()> {
+        let invalid = |message: &str| {
+            Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("{}: {}", message, value),
+            ))
+        };
+        match self {
+            ConfigType::Repo => {
+                let path = Path::new(value);
+                if !path.is_absolute() {
+                    return invalid("The repo must be an absolute path");
+                }
+                if !path.is_dir() {
+                    return invalid("The repo does not exist");
+                }
+                if !path.join(".git").exists() {
+                    return invalid("The repo is not a git repo");
+                }
+                Ok(())
+            }
+            ConfigType::File => {
+                // Ideas are always written inside the repo
+                let path = Path::new(value);
+                if value.is_empty()
+                    || path
+                        .components()
+                        .any(|component| !matches!(component, Component::Normal(_)))
+                {
+                    return invalid("The idea file must be a path inside the repo");
+                }
+                Ok(())
+            }
+            ConfigType::Branch => match git2::Branch::name_is_valid(value) {
+                Ok(true) => Ok(()),
+                _ => invalid("Not a valid branch name"),
+            },
+            ConfigType::Remote => match git2::Remote::is_valid_name(value) {
+                true => Ok(()),
+                false => invalid("Not a valid remote name"),
+            },
+            ConfigType::Storage => value.parse::<StorageLayout>().map(|_| ()),
+            ConfigType::Position => value.parse::<IdeaPosition>().map(|_| ()),
+            ConfigType::Template => template_path(Path::new(""), value).map(|_| ()),
+            ConfigType::Editor | ConfigType::Pager => find_program(value).map(|_| ()),
+        }
+    }
+}
+
+impl FromStr for ConfigType {
+    type Err = io::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ConfigType::ALL
+            .into_iter()
+            .find(|config_type| config_type.key() == value)
+            .ok_or_else(|| {
+                let keys: Vec<&str> = ConfigType::ALL.iter().map(ConfigType::key).collect();
+                io::Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unknown setting: {}, use one of {}", value, keys.join(", ")),
+                )
+            })
+    }
 }
 
 pub trait ConfigManagement {
     fn config_dir_create(&self) -> io::Result<()>;
     fn config_dir_exists(&self) -> bool;
     fn config_read(&self, config_type: ConfigType) -> io::Result<String>;
+    fn config_origin(&self, config_type: ConfigType) -> io::Result<ConfigOrigin>;
     fn config_write(&self, config_type: ConfigType, value: String) -> io::Result<()>;
+    fn config_unset(&self, config_type: ConfigType) -> io::Result<()>;
+    /// Every value stored in config.json for the profile in use
+    fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>>;
+    /// config.json as it's meant to be edited by hand
+    fn config_text_read(&self) -> io::Result<String>;
+    /// Replace config.json with `text`, if the values that changed are valid
+    fn config_text_write(&self, text: &str) -> io::Result<()>;
     fn config_rm(&self) -> io::Result<()>;
+    fn pending_push_mark(&self) -> io::Result<()>;
+    fn pending_push_clear(&self) -> io::Result<()>;
+    fn pending_push_exists(&self) -> bool;
+    fn template_read(&self, name: &str) -> io::Result<String>;
+    fn profiles_read(&self) -> io::Result<Profiles>;
+    fn profiles_write(&self, profiles: Profiles) -> io::Result<()>;
 }
 
 #[derive(Default)]
-pub struct ConfigManager;
+pub struct ConfigManager {
+    // Profile picked on the command line, otherwise the default one is used
+    profile: Option<String>,
+    // Values given on the command line
+    overrides: Vec<(ConfigType, String)>,
+    local: Option<LocalConfig>,
+}
 
 impl ConfigManagement for ConfigManager {
     fn config_dir_create(&self) -> io::Result<()> {
@@ -40,27 +323,120 @@ impl ConfigManagement for ConfigManager {
     }
 
     fn config_read(&self, config_type: ConfigType) -> io::Result<String> {
-        let config = self.config()?;
-        let config_value = match config_type {
-            ConfigType::Repo => config.repo.display().to_string(),
-        };
-        Ok(config_value)
+        self.config_lookup(config_type).map(|(value, _)| value)
+    }
+
+    fn config_origin(&self, config_type: ConfigType) -> io::Result<ConfigOrigin> {
+        self.config_lookup(config_type).map(|(_, origin)| origin)
     }
 
     fn config_write(&self, config_type: ConfigType, value: String) -> io::Result<()> {
-        let config_path = self.config_path()?;
+        let mut config = self.config_or_default()?;
+
+        // Writing to a profile that doesn't exist yet creates it
+        let name = self.profile_name(&config);
+        let profile = if name == DEFAULT_PROFILE {
+            &mut config.profile
+        } else {
+            config.profiles.entry(name).or_default()
+        };
+        match config_type {
+            ConfigType::Repo => profile.repo = Some(PathBuf::from(value)),
+            ConfigType::Branch => profile.branch = Some(value),
+            ConfigType::Remote => profile.remote = Some(value),
+            ConfigType::Storage => profile.storage = Some(value),
+            ConfigType::Template => profile.template = Some(value),
+            ConfigType::File => profile.file = Some(value),
+            ConfigType::Position => config.position = Some(value),
+            ConfigType::Editor => config.editor = Some(value),
+            ConfigType::Pager => config.pager = Some(value),
+        }
 
-        // Create file if it doesn't exist, otherwise get it
-        let mut file = fs::File::create(config_path)?;
+        self.config_save(&config)
+    }
 
-        let mut config = self.config()?;
+    fn config_unset(&self, config_type: ConfigType) -> io::Result<()> {
+        let mut config = self.config_or_default()?;
+
+        let name = self.profile_name(&config);
+        let profile = if name == DEFAULT_PROFILE {
+            &mut config.profile
+        } else {
+            match config.profiles.get_mut(&name) {
+                Some(profile) => profile,
+                None => return Ok(()),
+            }
+        };
         match config_type {
-            ConfigType::Repo => config.repo = PathBuf::from(value),
+            ConfigType::Repo => profile.repo = None,
+            ConfigType::Branch => profile.branch = None,
+            ConfigType::Remote => profile.remote = None,
+            ConfigType::Storage => profile.storage = None,
+            ConfigType::Template => profile.template = None,
+            ConfigType::File => profile.file = None,
+            ConfigType::Position => config.position = None,
+            ConfigType::Editor => config.editor = None,
+            ConfigType::Pager => config.pager = None,
         }
 
-        let json = serde_json::to_string(&config)?;
+        self.config_save(&config)
+    }
+
+    fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+        let config = self.config_or_default()?;
+        let name = self.profile_name(&config);
+        Ok(ConfigType::ALL
+            .into_iter()
+            .filter_map(|config_type| {
+                config
+                    .value(&name, config_type)
+                    .map(|value| (config_type, value))
+            })
+            .collect())
+    }
+
+    fn config_text_read(&self) -> io::Result<String> {
+        Ok(serde_json::to_string_pretty(&self.config_or_default()?)? + "\n")
+    }
+
+    fn config_text_write(&self, text: &str) -> io::Result<()> {
+        let invalid = |err: serde_json::Error| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("The config is not valid: {}", err),
+            )
+        };
+        let mut value = serde_json::from_str(text).map_err(invalid)?;
+        migrate(&mut value)?;
+        let config: Config = serde_json::from_value(value).map_err(invalid)?;
+
+        if let Some(name) = &config.default_profile {
+            if name != DEFAULT_PROFILE && !config.profiles.contains_key(name) {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("The default profile does not exist: {}", name),
+                ));
+            }
+        }
+
+        // Only what changed is checked, a repo that's missing for now can stay
+        let current = self.config_or_default()?;
+        let names = iter::once(DEFAULT_PROFILE).chain(config.profiles.keys().map(String::as_str));
+        for name in names {
+            for config_type in ConfigType::ALL {
+                let changed = config
+                    .value(name, config_type)
+                    .filter(|value| Some(value) != current.value(name, config_type).as_ref());
+                if let Some(value) = changed {
+                    config_type.validate(&value).map_err(|err| match name {
+                        DEFAULT_PROFILE => err,
+                        _ => io::Error::new(err.kind(), format!("{} (profile {})", err, name)),
+                    })?;
+                }
+            }
+        }
 
-        file.write_all(json.as_bytes())
+        self.config_save(&config)
     }
 
     fn config_rm(&self) -> io::Result<()> {
@@ -69,13 +445,189 @@ impl ConfigManagement for ConfigManager {
         fs::metadata(&config_path)?;
         fs::remove_file(&config_path)
     }
+
+    fn pending_push_mark(&self) -> io::Result<()> {
+        self.pending_push_path()
+            .and_then(|path| fs::write(path, ""))
+    }
+
+    fn pending_push_clear(&self) -> io::Result<()> {
+        let pending_push_path = self.pending_push_path()?;
+        match fs::remove_file(pending_push_path) {
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    fn pending_push_exists(&self) -> bool {
+        self.pending_push_path().and_then(fs::metadata).is_ok()
+    }
+
+    fn template_read(&self, name: &str) -> io::Result<String> {
+        let templates_dir = self.config_dir_path()?.join(TEMPLATES_DIR_NAME);
+        fs::read_to_string(template_path(&templates_dir, name)?)
+    }
+
+    fn profiles_read(&self) -> io::Result<Profiles> {
+        let config = self.config()?;
+        let mut profiles = config.profiles;
+        if config.profile.repo.is_some() {
+            profiles.insert(DEFAULT_PROFILE.to_string(), config.profile);
+        }
+        Ok(Profiles {
+            default: config
+                .default_profile
+                .unwrap_or_else(|| DEFAULT_PROFILE.to_string()),
+            profiles,
+        })
+    }
+
+    fn profiles_write(&self, mut profiles: Profiles) -> io::Result<()> {
+        let mut config = self.config_or_default()?;
+        // Keep the unknown settings even when the default profile is removed
+        let extra = mem::take(&mut config.profile.extra);
+        config.profile = profiles
+            .profiles
+            .remove(DEFAULT_PROFILE)
+            .unwrap_or_default();
+        for (key, value) in extra {
+            config.profile.extra.entry(key).or_insert(value);
+        }
+        config.default_profile = Some(profiles.default).filter(|name| name != DEFAULT_PROFILE);
+        config.profiles = profiles.profiles;
+
+        self.config_save(&config)
+    }
 }
 
 impl ConfigManager {
+    pub fn with_profile(mut self, profile: Option<String>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Use `value`, given on the command line, over anything configured
+    pub fn with_override(mut self, config_type: ConfigType, value: Option<String>) -> Self {
+        if let Some(value) = value {
+            self.overrides.push((config_type, value));
+        }
+        self
+    }
+
+    /// Use the nearest `.eureka.toml` in `dir` or one of its parents
+    pub fn with_local_config(mut self, dir: &Path) -> io::Result<Self> {
+        let path = match dir
+            .ancestors()
+            .map(|dir| dir.join(LOCAL_CONFIG_FILE_NAME))
+            .find(|path| path.is_file())
+        {
+            Some(path) => path,
+            None => return Ok(self),
+        };
+
+        let contents = fs::read_to_string(&path)?;
+        let mut local: LocalConfig = toml::from_str(&contents).map_err(|err| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("Could not read {}: {}", path.display(), err),
+            )
+        })?;
+        local.path = path;
+        self.local = Some(local);
+        Ok(self)
+    }
+
+    /// Upgrade a config from an older version, or import the one from eureka 1.x,
+    /// and save it. Configs that are up to date, or missing, are left alone
+    pub fn migrate_config(&self) -> io::Result<()> {
+        match self.config_migrated() {
+            Ok((config, true)) => self.config_save(&config),
+            Ok(_) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Find a value and where it comes from. The command line wins over the
+    /// nearest `.eureka.toml`, which wins over the profile in config.json.
+    fn config_lookup(&self, config_type: ConfigType) -> io::Result<(String, ConfigOrigin)> {
+        if let Some((_, value)) = self.overrides.iter().find(|(key, _)| *key == config_type) {
+            return Ok((value.clone(), ConfigOrigin::CommandLine));
+        }
+        if let Some(local) = &self.local {
+            if let Some(value) = local.value(config_type) {
+                return Ok((value, ConfigOrigin::Local(local.path.clone())));
+            }
+        }
+
+        let mut config = self.config()?;
+        let global = ConfigOrigin::Global(self.config_path()?);
+        let name = self.profile_name(&config);
+        let (profile, profile_origin) = if name == DEFAULT_PROFILE {
+            (config.profile, global.clone())
+        } else {
+            let profile = config.profiles.remove(&name).ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "No profile called {0}, add it with `eureka config profiles add {0} REPO`",
+                        name
+                    ),
+                )
+            })?;
+            (profile, ConfigOrigin::Profile(name))
+        };
+        let (config_value, origin) = match config_type {
+            // A config without a repo has always read as an empty one
+            ConfigType::Repo => (
+                Some(
+                    profile
+                        .repo
+                        .map(|repo| repo.display().to_string())
+                        .unwrap_or_default(),
+                ),
+                profile_origin,
+            ),
+            ConfigType::Branch => (profile.branch, profile_origin),
+            ConfigType::Remote => (profile.remote, profile_origin),
+            ConfigType::Storage => (profile.storage, profile_origin),
+            ConfigType::Template => (profile.template, profile_origin),
+            ConfigType::File => (profile.file, profile_origin),
+            ConfigType::Position => (config.position, global),
+            ConfigType::Editor => (config.editor, global),
+            ConfigType::Pager => (config.pager, global),
+        };
+        config_value.map(|value| (value, origin)).ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::NotFound,
+                format!("{:?} is not set in config", config_type),
+            )
+        })
+    }
+
     fn config_path(&self) -> io::Result<PathBuf> {
         Ok(self.config_dir_path()?.join(CONFIG_FILE_NAME))
     }
 
+    // Every profile has its own repo, so each one keeps track of its own push
+    fn pending_push_path(&self) -> io::Result<PathBuf> {
+        let name = self.profile_name(&self.config_or_default()?);
+        let file_name = if name == DEFAULT_PROFILE {
+            PENDING_PUSH_FILE_NAME.to_string()
+        } else {
+            format!("{}-{}", PENDING_PUSH_FILE_NAME, name)
+        };
+        Ok(self.config_dir_path()?.join(file_name))
+    }
+
+    fn profile_name(&self, config: &Config) -> String {
+        self.profile
+            .clone()
+            .or_else(|| self.local.as_ref().and_then(|local| local.profile.clone()))
+            .or_else(|| config.default_profile.clone())
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+    }
+
     fn config_dir_path(&self) -> io::Result<PathBuf> {
         self.resolve_xdg_config_home()
             .or_else(|| Some(home_dir().unwrap().join(".config").join("eureka")))
@@ -88,19 +640,80 @@ impl ConfigManager {
     }
 
     fn config(&self) -> io::Result<Config> {
-        let config_file = self.config_path()?;
-        // Make sure file exists
-        fs::metadata(&config_file)?;
+        self.config_migrated().map(|(config, _)| config)
+    }
 
-        let mut file = fs::File::open(&config_file)?;
-        let mut contents = String::new();
-        file.read_to_string(&mut contents)?;
+    // The config upgraded to the current version, and whether that changed it.
+    // Only `migrate_config` saves the upgrade, reading never writes the config
+    fn config_migrated(&self) -> io::Result<(Config, bool)> {
+        let mut value = match fs::read_to_string(self.config_path()?) {
+            Ok(contents) if contents.is_empty() => return Ok((Config::new(), false)),
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(err) if err.kind() == ErrorKind::NotFound => self.legacy_config()?.ok_or(err)?,
+            Err(err) => return Err(err),
+        };
 
-        if contents.is_empty() {
-            return Ok(Config::default());
+        let migrated = migrate(&mut value)?;
+        Ok((serde_json::from_value(value)?, migrated))
+    }
+
+    // eureka 1.x kept each setting in a plain text file of its own
+    fn legacy_config(&self) -> io::Result<Option<Value>> {
+        let legacy_dir = match home_dir() {
+            Some(home) => home.join(LEGACY_CONFIG_DIR_NAME),
+            None => return Ok(None),
+        };
+
+        let mut config = Map::new();
+        for config_type in [ConfigType::Repo, ConfigType::Branch, ConfigType::Editor] {
+            match fs::read_to_string(legacy_dir.join(config_type.key())) {
+                Ok(value) if !value.trim().is_empty() => {
+                    config.insert(
+                        config_type.key().to_string(),
+                        Value::String(value.trim().to_string()),
+                    );
+                }
+                Ok(_) => {}
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
         }
 
-        Ok(serde_json::from_str(&contents)?)
+        // Without a repo there's nothing worth keeping, so set up from scratch
+        if !config.contains_key(ConfigType::Repo.key()) {
+            return Ok(None);
+        }
+        debug!("Importing the config from {}", legacy_dir.display());
+        Ok(Some(Value::Object(config)))
+    }
+
+    fn config_or_default(&self) -> io::Result<Config> {
+        match self.config() {
+            Ok(config) => Ok(config),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Config::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    // Write to a temporary file next to the config and move it into place,
+    // so a crash half way through never leaves a broken config behind
+    fn config_save(&self, config: &Config) -> io::Result<()> {
+        let json = serde_json::to_string(config)?;
+        let config_dir = self.config_dir_path()?;
+        let tmp_path = config_dir.join(CONFIG_TMP_FILE_NAME);
+
+        // A config imported from eureka 1.x has nowhere to go yet
+        fs::create_dir_all(&config_dir)?;
+
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(json.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, config_dir.join(CONFIG_FILE_NAME))?;
+
+        // Make the rename itself durable
+        #[cfg(unix)]
+        fs::File::open(&config_dir)?.sync_all()?;
+        Ok(())
     }
 
     fn resolve_xdg_config_home(&self) -> Option<PathBuf> {
@@ -111,21 +724,59 @@ impl ConfigManager {
     }
 }
 
+/// Upgrade `value` to the current version of the config, returning whether
+/// anything changed. Configs from a newer eureka are left as they are.
+fn migrate(value: &mut Value) -> io::Result<bool> {
+    let invalid = |message: &str| io::Error::new(ErrorKind::InvalidData, message.to_string());
+    let config = value
+        .as_object_mut()
+        .ok_or_else(|| invalid("The config is not a JSON object"))?;
+    let version = match config.get("version") {
+        Some(version) => version
+            .as_u64()
+            .ok_or_else(|| invalid("The config version is not a number"))?,
+        None => 0,
+    };
+    if version >= CONFIG_VERSION {
+        return Ok(false);
+    }
+
+    // Configs without a version have the same format as version 1
+    for migration in &MIGRATIONS[version.max(1) as usize - 1..] {
+        migration(config);
+    }
+    debug!(
+        "Migrated the config from version {} to {}",
+        version, CONFIG_VERSION
+    );
+    config.insert(String::from("version"), Value::from(CONFIG_VERSION));
+    Ok(true)
+}
+
 #[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
-    use crate::config_manager::{ConfigManagement, ConfigManager, ConfigType};
+    use crate::config_manager::{
+        ConfigManagement, ConfigManager, ConfigOrigin, ConfigType, Profile, DEFAULT_PROFILE,
+    };
     use std::io::{Read, Write};
     use std::path::{Path, PathBuf};
+    use std::sync::{Mutex, MutexGuard};
     use std::{env, fs, io, path};
     use tempfile::TempDir;
 
     type TestResult = Result<(), Box<dyn std::error::Error>>;
 
+    // Configs as written by earlier versions of eureka
+    const FIXTURE_1_X_REPO: &str = include_str!("../tests/fixtures/config/1.x/repo");
+    const FIXTURE_1_X_BRANCH: &str = include_str!("../tests/fixtures/config/1.x/branch");
+    const FIXTURE_1_X_EDITOR: &str = include_str!("../tests/fixtures/config/1.x/editor");
+    const FIXTURE_2_0: &str = include_str!("../tests/fixtures/config/2.0.json");
+
     #[test]
     fn test_config_manager__config_dir_path() -> TestResult {
         let cm = ConfigManager::default();
-        let (_config_dir, tmp_dir) = set_config_dir()?;
+        let (_config_dir, tmp_dir, _env) = set_config_dir()?;
 
         // XDG_CONFIG_HOME is set in Github Actions so let's unset it
         env::remove_var("XDG_CONFIG_HOME");
@@ -144,6 +795,7 @@ mod tests {
     fn test_config_manager__config_dir_path__when__xdg_config_home_env_var_set() -> TestResult {
         use std::path::Path;
 
+        let _env = lock_env();
         let cm = ConfigManager::default();
         env::set_var("XDG_CONFIG_HOME", "/specific-path/.config");
         assert_eq!(
@@ -164,7 +816,7 @@ mod tests {
     #[test]
     fn test_config_manager__config_dir_create() -> TestResult {
         let cm = ConfigManager::default();
-        let (_config_dir, _tmp_dir) = set_config_dir()?;
+        let (_config_dir, _tmp_dir, _env) = set_config_dir()?;
 
         let actual = cm.config_dir_create();
 
@@ -177,7 +829,7 @@ mod tests {
     #[test]
     fn test_config_manager__config_dir_exists__success() -> TestResult {
         let cm = ConfigManager::default();
-        let (_config_dir, _tmp_dir) = set_and_create_config_dir()?;
+        let (_config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
 
         let config_dir_exists = cm.config_dir_exists();
 
@@ -190,7 +842,7 @@ mod tests {
     #[test]
     fn test_config_manager__config_dir_exists__failure() -> TestResult {
         let cm = ConfigManager::default();
-        let (_config_dir, _tmp_dir) = set_config_dir()?;
+        let (_config_dir, _tmp_dir, _env) = set_config_dir()?;
 
         // XDG_CONFIG_HOME is set in Github Actions so let's unset it
         env::remove_var("XDG_CONFIG_HOME");
@@ -207,7 +859,7 @@ mod tests {
     #[test]
     fn test_config_manager__config_read__success() -> TestResult {
         let cm = ConfigManager::default();
-        let (config_dir, _tmp_dir) = set_and_create_config_dir()?;
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
         let mut file =
             fs::File::create(path::Path::new(&config_dir.join("config.json").as_os_str()))?;
         file.write_all("{\"repo\": \"this-repo-path-value\"}".as_bytes())?;
@@ -224,7 +876,7 @@ mod tests {
     #[test]
     fn test_config_manager__config_read__file_is_empty__default_config() -> TestResult {
         let cm = ConfigManager::default();
-        let (config_dir, _tmp_dir) = set_and_create_config_dir()?;
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
         // Create file but leave it empty
         let _file = fs::File::create(path::Path::new(&config_dir.join("config.json").as_os_str()))?;
 
@@ -240,7 +892,7 @@ mod tests {
     #[test]
     fn test_config_manager__config_read__when__file_does_not_exist__failure() -> TestResult {
         let cm = ConfigManager::default();
-        let (_config_dir, _tmp_dir) = set_and_create_config_dir()?;
+        let (_config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
 
         let actual = cm.config_read(ConfigType::Repo).map_err(|e| e.kind());
         let expected = Err(io::ErrorKind::NotFound);
@@ -255,7 +907,7 @@ mod tests {
     fn test_config_manager__config_write__config_file_does_not_already_exist__success() -> TestResult
     {
         let cm = ConfigManager::default();
-        let (config_dir, _tmp_dir) = set_and_create_config_dir()?;
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
 
         let write_result = cm.config_write(ConfigType::Repo, String::from("this-specific-value"));
 
@@ -265,7 +917,7 @@ mod tests {
 
         // Assert file contents
         let contents = get_file_contents(&config_dir)?;
-        let expected = "{\"repo\":\"this-specific-value\"}";
+        let expected = "{\"version\":1,\"repo\":\"this-specific-value\"}";
 
         assert_eq!(contents, expected);
         Ok(())
@@ -274,7 +926,7 @@ mod tests {
     #[test]
     fn test_config_manager__config_write__config_file_already_exists__success() -> TestResult {
         let cm = ConfigManager::default();
-        let (config_dir, _tmp_dir) = set_and_create_config_dir()?;
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
         // Create file but leave it empty
         let _file = fs::File::create(path::Path::new(&config_dir.join("config.json").as_os_str()))?;
 
@@ -289,16 +941,429 @@ mod tests {
         let mut contents = String::new();
         file.read_to_string(&mut contents)?;
 
-        let expected = "{\"repo\":\"this-specific-value\"}";
+        let expected = "{\"version\":1,\"repo\":\"this-specific-value\"}";
 
         assert_eq!(contents, expected);
         Ok(())
     }
 
+    #[test]
+    fn test_config_manager__config_read__branch_not_set__failure() -> TestResult {
+        let cm = ConfigManager::default();
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        let mut file =
+            fs::File::create(path::Path::new(&config_dir.join("config.json").as_os_str()))?;
+        file.write_all("{\"repo\": \"this-repo-path-value\"}".as_bytes())?;
+
+        let actual = cm.config_read(ConfigType::Branch).map_err(|e| e.kind());
+        let expected = Err(io::ErrorKind::NotFound);
+
+        env::remove_var("HOME");
+
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__config_write__keeps_other_values__success() -> TestResult {
+        let cm = ConfigManager::default();
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+
+        cm.config_write(ConfigType::Repo, String::from("this-repo-value"))?;
+        cm.config_write(ConfigType::Branch, String::from("this-branch-value"))?;
+        cm.config_write(ConfigType::Remote, String::from("this-remote-value"))?;
+
+        let repo = cm.config_read(ConfigType::Repo)?;
+        let branch = cm.config_read(ConfigType::Branch)?;
+        let remote = cm.config_read(ConfigType::Remote)?;
+        let contents = get_file_contents(&config_dir)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(repo, "this-repo-value");
+        assert_eq!(branch, "this-branch-value");
+        assert_eq!(remote, "this-remote-value");
+        assert_eq!(
+            contents,
+            "{\"version\":1,\"repo\":\"this-repo-value\",\"branch\":\"this-branch-value\",\"remote\":\"this-remote-value\"}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__config_write__every_setting__round_trip() -> TestResult {
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        let cm = ConfigManager::default();
+        let work = ConfigManager::default().with_profile(Some(String::from("work")));
+
+        for config_type in ConfigType::ALL {
+            cm.config_write(config_type, format!("{}-value", config_type.key()))?;
+            work.config_write(config_type, format!("work-{}-value", config_type.key()))?;
+        }
+
+        let values = ConfigType::ALL.map(|config_type| cm.config_read(config_type).unwrap());
+        let work_values = ConfigType::ALL.map(|config_type| work.config_read(config_type).unwrap());
+        let tmp_file_left = config_dir.join("config.json.tmp").exists();
+
+        env::remove_var("HOME");
+
+        // Only the repo settings belong to a profile, the rest is shared so the last write wins
+        assert_eq!(
+            values,
+            ConfigType::ALL.map(|config_type| match config_type {
+                ConfigType::Position | ConfigType::Editor | ConfigType::Pager => {
+                    format!("work-{}-value", config_type.key())
+                }
+                _ => format!("{}-value", config_type.key()),
+            })
+        );
+        assert_eq!(
+            work_values,
+            ConfigType::ALL.map(|config_type| format!("work-{}-value", config_type.key()))
+        );
+        assert!(!tmp_file_left);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__config_write__keeps_unknown_fields__success() -> TestResult {
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        fs::write(
+            config_dir.join("config.json"),
+            "{\"repo\":\"/personal\",\"theme\":{\"accent\":\"blue\"},\"profiles\":{\"work\":{\"repo\":\"/work\",\"sign\":true}}}",
+        )?;
+        let cm = ConfigManager::default();
+        let work = ConfigManager::default().with_profile(Some(String::from("work")));
+
+        cm.config_write(ConfigType::Branch, String::from("trunk"))?;
+        work.config_write(ConfigType::Remote, String::from("upstream"))?;
+        let repo = cm.config_read(ConfigType::Repo)?;
+        let contents: serde_json::Value = serde_json::from_str(&get_file_contents(&config_dir)?)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(repo, "/personal");
+        assert_eq!(
+            contents,
+            serde_json::json!({
+                "version": 1,
+                "repo": "/personal",
+                "branch": "trunk",
+                "theme": {"accent": "blue"},
+                "profiles": {"work": {"repo": "/work", "remote": "upstream", "sign": true}},
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__config_write__profile__success() -> TestResult {
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        let cm = ConfigManager::default();
+        let work = ConfigManager::default().with_profile(Some(String::from("work")));
+
+        cm.config_write(ConfigType::Repo, String::from("/personal"))?;
+        work.config_write(ConfigType::Repo, String::from("/work"))?;
+        work.config_write(ConfigType::Template, String::from("meeting"))?;
+        work.config_write(ConfigType::Editor, String::from("vim"))?;
+
+        let repo = cm.config_read(ConfigType::Repo)?;
+        let work_repo = work.config_read(ConfigType::Repo)?;
+        let template = cm.config_read(ConfigType::Template).map_err(|e| e.kind());
+        let editor = cm.config_read(ConfigType::Editor)?;
+        let missing = ConfigManager::default()
+            .with_profile(Some(String::from("team")))
+            .config_read(ConfigType::Repo)
+            .map_err(|e| e.kind());
+        let contents = get_file_contents(&config_dir)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(repo, "/personal");
+        assert_eq!(work_repo, "/work");
+        assert_eq!(template, Err(io::ErrorKind::NotFound));
+        // Only the repo settings belong to a profile
+        assert_eq!(editor, "vim");
+        assert_eq!(missing, Err(io::ErrorKind::InvalidInput));
+        assert_eq!(
+            contents,
+            "{\"version\":1,\"repo\":\"/personal\",\"editor\":\"vim\",\"profiles\":{\"work\":{\"repo\":\"/work\",\"template\":\"meeting\"}}}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__profiles_write__default_profile__success() -> TestResult {
+        let (_config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        let cm = ConfigManager::default();
+        cm.config_write(ConfigType::Repo, String::from("/personal"))?;
+
+        let mut profiles = cm.profiles_read()?;
+        profiles.profiles.insert(
+            String::from("work"),
+            Profile {
+                repo: Some(PathBuf::from("/work")),
+                branch: Some(String::from("trunk")),
+                ..Profile::default()
+            },
+        );
+        profiles.default = String::from("work");
+        cm.profiles_write(profiles.clone())?;
+
+        let read_back = cm.profiles_read()?;
+        let repo = cm.config_read(ConfigType::Repo)?;
+        let branch = cm.config_read(ConfigType::Branch)?;
+        let personal_repo = ConfigManager::default()
+            .with_profile(Some(String::from(DEFAULT_PROFILE)))
+            .config_read(ConfigType::Repo)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(read_back, profiles);
+        assert_eq!(
+            read_back.profiles.keys().collect::<Vec<_>>(),
+            ["default", "work"]
+        );
+        assert_eq!(repo, "/work");
+        assert_eq!(branch, "trunk");
+        assert_eq!(personal_repo, "/personal");
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__profiles_write__keeps_unknown_fields__success() -> TestResult {
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        fs::write(
+            config_dir.join("config.json"),
+            "{\"repo\":\"/personal\",\"theme\":\"dark\",\"profiles\":{\"work\":{\"repo\":\"/work\",\"sign\":true}}}",
+        )?;
+        let cm = ConfigManager::default();
+
+        // Removing the default profile keeps the settings that aren't part of it
+        let mut profiles = cm.profiles_read()?;
+        profiles.profiles.remove(DEFAULT_PROFILE);
+        profiles.default = String::from("work");
+        cm.profiles_write(profiles)?;
+        let contents: serde_json::Value = serde_json::from_str(&get_file_contents(&config_dir)?)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(
+            contents,
+            serde_json::json!({
+                "version": 1,
+                "theme": "dark",
+                "default_profile": "work",
+                "profiles": {"work": {"repo": "/work", "sign": true}},
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__with_local_config__precedence() -> TestResult {
+        let (config_dir, tmp_dir, _env) = set_and_create_config_dir()?;
+        let project = tmp_dir.path().join("project");
+        let nested = project.join("src").join("nested");
+        fs::create_dir_all(&nested)?;
+        fs::write(
+            config_dir.join("config.json"),
+            "{\"repo\":\"/personal\",\"branch\":\"trunk\",\"editor\":\"vim\",\"profiles\":{\"work\":{\"repo\":\"/work\",\"remote\":\"upstream\"}}}",
+        )?;
+        fs::write(
+            project.join(".eureka.toml"),
+            "profile = \"work\"\nrepo = \".\"\nfile = \"IDEAS.md\"\neditor = \"nano\"\n",
+        )?;
+
+        let cm = ConfigManager::default()
+            .with_override(ConfigType::Pager, Some(String::from("bat")))
+            .with_local_config(&nested)?;
+        let values = [
+            ConfigType::Repo,
+            ConfigType::File,
+            ConfigType::Remote,
+            ConfigType::Editor,
+            ConfigType::Pager,
+        ]
+        .map(|config_type| cm.config_read(config_type).unwrap());
+        let origins = [ConfigType::Repo, ConfigType::Remote, ConfigType::Pager]
+            .map(|config_type| cm.config_origin(config_type).unwrap());
+        // The profile picked in .eureka.toml has no branch, and the default profile's isn't used
+        let branch = cm.config_read(ConfigType::Branch).map_err(|e| e.kind());
+        let outside = ConfigManager::default()
+            .with_local_config(tmp_dir.path())?
+            .config_read(ConfigType::Repo)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(
+            values,
+            [
+                project.display().to_string(),
+                String::from("IDEAS.md"),
+                String::from("upstream"),
+                String::from("nano"),
+                String::from("bat"),
+            ]
+        );
+        assert_eq!(
+            origins,
+            [
+                ConfigOrigin::Local(project.join(".eureka.toml")),
+                ConfigOrigin::Profile(String::from("work")),
+                ConfigOrigin::CommandLine,
+            ]
+        );
+        assert_eq!(branch, Err(io::ErrorKind::NotFound));
+        assert_eq!(outside, "/personal");
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__with_local_config__unknown_key__failure() -> TestResult {
+        let tmp_dir = TempDir::new()?;
+        fs::write(tmp_dir.path().join(".eureka.toml"), "repository = \".\"\n")?;
+
+        let actual = ConfigManager::default()
+            .with_local_config(tmp_dir.path())
+            .map(|_| ())
+            .map_err(|e| e.kind());
+
+        assert_eq!(actual, Err(io::ErrorKind::InvalidData));
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_type__from_str() {
+        let parsed =
+            ConfigType::ALL.map(|config_type| config_type.key().parse::<ConfigType>().ok());
+        let unknown = "repository".parse::<ConfigType>().map_err(|e| e.kind());
+
+        assert_eq!(parsed, ConfigType::ALL.map(Some));
+        assert_eq!(unknown, Err(io::ErrorKind::InvalidInput));
+    }
+
+    #[test]
+    fn test_config_type__validate() -> TestResult {
+        let tmp_dir = TempDir::new()?;
+        let repo = tmp_dir.path().join("ideas");
+        fs::create_dir_all(repo.join(".git"))?;
+        let not_a_repo = tmp_dir.path().display().to_string();
+        let missing = tmp_dir.path().join("missing").display().to_string();
+        let repo = repo.display().to_string();
+
+        let valid = [
+            (ConfigType::Repo, repo.as_str()),
+            (ConfigType::File, "docs/IDEAS.md"),
+            (ConfigType::Branch, "feature/ideas"),
+            (ConfigType::Remote, "upstream"),
+            (ConfigType::Storage, "file-per-idea"),
+            (ConfigType::Position, "top"),
+            (ConfigType::Template, "meeting"),
+        ];
+        let invalid = [
+            (ConfigType::Repo, "ideas"),
+            (ConfigType::Repo, missing.as_str()),
+            (ConfigType::Repo, not_a_repo.as_str()),
+            (ConfigType::File, "../IDEAS.md"),
+            (ConfigType::File, "/IDEAS.md"),
+            (ConfigType::Branch, "bad..name"),
+            (ConfigType::Remote, "no spaces"),
+            (ConfigType::Storage, "database"),
+            (ConfigType::Position, "middle"),
+            (ConfigType::Template, "../meeting"),
+            (ConfigType::Editor, "surely-not-an-editor-on-path"),
+        ];
+
+        for (config_type, value) in valid {
+            assert!(config_type.validate(value).is_ok(), "{}", value);
+        }
+        for (config_type, value) in invalid {
+            assert!(config_type.validate(value).is_err(), "{}", value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__config_unset__success() -> TestResult {
+        let (_config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        let cm = ConfigManager::default();
+        let work = ConfigManager::default().with_profile(Some(String::from("work")));
+        cm.config_write(ConfigType::Repo, String::from("/personal"))?;
+        cm.config_write(ConfigType::Editor, String::from("vim"))?;
+        work.config_write(ConfigType::Repo, String::from("/work"))?;
+        work.config_write(ConfigType::Branch, String::from("trunk"))?;
+
+        work.config_unset(ConfigType::Branch)?;
+        work.config_unset(ConfigType::Editor)?;
+        let work_list = work.config_list()?;
+        let list = cm.config_list()?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(work_list, [(ConfigType::Repo, String::from("/work"))]);
+        assert_eq!(list, [(ConfigType::Repo, String::from("/personal"))]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__config_text_write__success() -> TestResult {
+        let (config_dir, tmp_dir, _env) = set_and_create_config_dir()?;
+        // A repo that's gone missing doesn't get in the way of other changes
+        fs::write(
+            config_dir.join("config.json"),
+            "{\"version\":1,\"repo\":\"/unmounted\",\"theme\":\"dark\"}",
+        )?;
+        let repo = tmp_dir.path().join("ideas");
+        fs::create_dir_all(repo.join(".git"))?;
+        let cm = ConfigManager::default();
+
+        let text = cm.config_text_read()?;
+        let edited = text.replace("\"theme\"", "\"branch\": \"trunk\",\n  \"theme\"");
+        cm.config_text_write(&edited)?;
+        let branch = cm.config_read(ConfigType::Branch)?;
+        let bad_branch = cm
+            .config_text_write(&edited.replace("trunk", "bad..name"))
+            .map_err(|e| e.kind());
+        let bad_repo = cm
+            .config_text_write(&edited.replace("/unmounted", "relative"))
+            .map_err(|e| e.kind());
+        let bad_profile = cm
+            .config_text_write(
+                &edited.replace("\"theme\"", "\"default_profile\": \"work\", \"theme\""),
+            )
+            .map_err(|e| e.kind());
+        let not_json = cm.config_text_write("{\"repo\":").map_err(|e| e.kind());
+        let good_repo =
+            cm.config_text_write(&edited.replace("/unmounted", &repo.display().to_string()));
+        let contents = get_file_contents(&config_dir)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(
+            text,
+            "{\n  \"version\": 1,\n  \"repo\": \"/unmounted\",\n  \"theme\": \"dark\"\n}\n"
+        );
+        assert_eq!(branch, "trunk");
+        assert_eq!(bad_branch, Err(io::ErrorKind::InvalidInput));
+        assert_eq!(bad_repo, Err(io::ErrorKind::InvalidInput));
+        assert_eq!(bad_profile, Err(io::ErrorKind::InvalidInput));
+        assert_eq!(not_json, Err(io::ErrorKind::InvalidData));
+        assert!(good_repo.is_ok());
+        assert_eq!(
+            contents,
+            format!(
+                "{{\"version\":1,\"repo\":\"{}\",\"branch\":\"trunk\",\"theme\":\"dark\"}}",
+                repo.display()
+            )
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_config_manager__config_rm__success() -> TestResult {
         let cm = ConfigManager::default();
-        let (config_dir, _tmp_dir) = set_and_create_config_dir()?;
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
         // Create file but leave it empty
         let _file = fs::File::create(path::Path::new(&config_dir.join("config.json").as_os_str()))?;
 
@@ -313,7 +1378,7 @@ mod tests {
     #[test]
     fn test_config_manager__config_rm__file_does_not_exist__failure() -> TestResult {
         let cm = ConfigManager::default();
-        let (_config_dir, _tmp_dir) = set_and_create_config_dir()?;
+        let (_config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
 
         let actual = cm.config_rm().map_err(|e| e.kind());
         let expected = Err(io::ErrorKind::NotFound);
@@ -324,7 +1389,153 @@ mod tests {
         Ok(())
     }
 
-    fn set_config_dir() -> io::Result<(PathBuf, TempDir)> {
+    #[test]
+    fn test_config_manager__pending_push__mark_and_clear() -> TestResult {
+        let cm = ConfigManager::default();
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+
+        let before = cm.pending_push_exists();
+        cm.pending_push_mark()?;
+        let marked = cm.pending_push_exists();
+        let marker_exists = config_dir.join("pending-push").exists();
+        cm.pending_push_clear()?;
+        let cleared = cm.pending_push_exists();
+        // Clearing when nothing is pending is fine
+        let cleared_again = cm.pending_push_clear();
+
+        env::remove_var("HOME");
+
+        assert!(!before);
+        assert!(marked);
+        assert!(marker_exists);
+        assert!(!cleared);
+        assert!(cleared_again.is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__migrate__1_x__success() -> TestResult {
+        let (config_dir, tmp_dir, _env) = set_config_dir()?;
+        let legacy_dir = tmp_dir.path().join(".eureka");
+        fs::create_dir_all(&legacy_dir)?;
+        fs::write(legacy_dir.join("repo"), FIXTURE_1_X_REPO)?;
+        fs::write(legacy_dir.join("branch"), FIXTURE_1_X_BRANCH)?;
+        fs::write(legacy_dir.join("editor"), FIXTURE_1_X_EDITOR)?;
+        let cm = ConfigManager::default();
+
+        let values = [ConfigType::Repo, ConfigType::Branch, ConfigType::Editor]
+            .map(|config_type| cm.config_read(config_type).unwrap());
+        let created_by_read = config_dir.join("config.json").exists();
+        cm.migrate_config()?;
+        let contents = get_file_contents(&config_dir)?;
+        let legacy_dir_kept = legacy_dir.join("repo").exists();
+
+        env::remove_var("HOME");
+
+        assert_eq!(values, ["/home/user/ideas", "master", "/usr/bin/vim"]);
+        assert!(!created_by_read);
+        assert_eq!(
+            contents,
+            "{\"version\":1,\"repo\":\"/home/user/ideas\",\"branch\":\"master\",\"editor\":\"/usr/bin/vim\"}"
+        );
+        assert!(legacy_dir_kept);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__migrate__1_x_without_repo__not_found() -> TestResult {
+        let (config_dir, tmp_dir, _env) = set_config_dir()?;
+        let legacy_dir = tmp_dir.path().join(".eureka");
+        fs::create_dir_all(&legacy_dir)?;
+        fs::write(legacy_dir.join("branch"), FIXTURE_1_X_BRANCH)?;
+        let cm = ConfigManager::default();
+
+        let actual = cm.config_read(ConfigType::Branch).map_err(|e| e.kind());
+        let migrated = cm.migrate_config();
+        let config_created = config_dir.join("config.json").exists();
+
+        env::remove_var("HOME");
+
+        assert_eq!(actual, Err(io::ErrorKind::NotFound));
+        assert!(migrated.is_ok());
+        assert!(!config_created);
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__migrate__2_0__success() -> TestResult {
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        fs::write(config_dir.join("config.json"), FIXTURE_2_0)?;
+        let cm = ConfigManager::default();
+
+        let repo = cm.config_read(ConfigType::Repo)?;
+        let contents_after_read = get_file_contents(&config_dir)?;
+        cm.migrate_config()?;
+        let contents = get_file_contents(&config_dir)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(repo, "/home/user/ideas");
+        assert_eq!(contents_after_read, FIXTURE_2_0);
+        assert_eq!(contents, "{\"version\":1,\"repo\":\"/home/user/ideas\"}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__migrate__newer_version__left_as_is() -> TestResult {
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        fs::write(
+            config_dir.join("config.json"),
+            "{\"version\":99,\"repo\":\"/ideas\",\"sync\":\"hourly\"}",
+        )?;
+        let cm = ConfigManager::default();
+
+        cm.migrate_config()?;
+        cm.config_write(ConfigType::Branch, String::from("trunk"))?;
+        let repo = cm.config_read(ConfigType::Repo)?;
+        let contents = get_file_contents(&config_dir)?;
+
+        env::remove_var("HOME");
+
+        assert_eq!(repo, "/ideas");
+        assert_eq!(
+            contents,
+            "{\"version\":99,\"repo\":\"/ideas\",\"branch\":\"trunk\",\"sync\":\"hourly\"}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_manager__migrate__invalid_version__failure() -> TestResult {
+        let (config_dir, _tmp_dir, _env) = set_and_create_config_dir()?;
+        fs::write(
+            config_dir.join("config.json"),
+            "{\"version\":\"2\",\"repo\":\"/ideas\"}",
+        )?;
+
+        let actual = ConfigManager::default()
+            .config_read(ConfigType::Repo)
+            .map_err(|e| e.kind());
+
+        env::remove_var("HOME");
+
+        assert_eq!(actual, Err(io::ErrorKind::InvalidData));
+        Ok(())
+    }
+
+    // Tests run in parallel but HOME and XDG_CONFIG_HOME are process-wide, so
+    // every test that changes them holds this lock until it returns
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_env() -> MutexGuard<'static, ()> {
+        // A failed test poisons the lock, which shouldn't fail the others
+        ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn set_config_dir() -> io::Result<(PathBuf, TempDir, MutexGuard<'static, ()>)> {
+        let env_guard = lock_env();
+        env::remove_var("XDG_CONFIG_HOME");
+
         let tmp_dir = TempDir::new()?;
         // Create the config dir. When tmp_dir is destroyed it will be deleted
         let config_dir = tmp_dir.path().join(".config").join("eureka");
@@ -335,17 +1546,17 @@ mod tests {
             Ok(tmp_dir.path().to_str().unwrap().to_string())
         );
 
-        // tmp_dir cannot be destroyed yet, so return it
-        Ok((config_dir, tmp_dir))
+        // tmp_dir and the env lock cannot be dropped yet, so return them
+        Ok((config_dir, tmp_dir, env_guard))
     }
 
-    fn set_and_create_config_dir() -> io::Result<(PathBuf, TempDir)> {
-        let (config_dir, tmp_dir) = set_config_dir()?;
+    fn set_and_create_config_dir() -> io::Result<(PathBuf, TempDir, MutexGuard<'static, ()>)> {
+        let (config_dir, tmp_dir, env_guard) = set_config_dir()?;
 
         fs::create_dir_all(&config_dir)?;
 
-        // tmp_dir cannot be destroyed yet, so return it
-        Ok((config_dir, tmp_dir))
+        // tmp_dir and the env lock cannot be dropped yet, so return them
+        Ok((config_dir, tmp_dir, env_guard))
     }
 
     fn get_file_contents(config_dir: &Path) -> io::Result<String> {
@@ -1,11 +1,33 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, FixedOffset, TimeZone};
+
+/// The parts of a commit eureka shows next to ideas
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitInfo {
+    pub id: git2::Oid,
+    pub time: DateTime<FixedOffset>,
+    pub summary: String,
+    pub message: String,
+}
 
 pub trait GitManagement {
     fn init(&mut self, repo_path: &str) -> Result<(), git2::Error>;
     fn checkout_branch(&self, branch_name: &str) -> Result<(), git2::Error>;
-    fn add(&self) -> Result<(), git2::Error>;
+    fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error>;
     fn commit(&self, subject: &str) -> Result<git2::Oid, git2::Error>;
-    fn push(&self, branch_name: &str) -> Result<(), git2::Error>;
+    fn push(&self, remote_name: &str, branch_name: &str) -> Result<(), git2::Error>;
+    fn pull(&self, remote_name: &str, branch_name: &str) -> Result<(), git2::Error>;
+    fn current_branch(&self) -> Result<Option<String>, git2::Error>;
+    fn remotes(&self) -> Result<Vec<String>, git2::Error>;
+    fn blame_lines(
+        &self,
+        path: &Path,
+        lines: &[usize],
+    ) -> Result<Vec<Option<CommitInfo>>, git2::Error>;
+    fn log(&self) -> Result<Vec<CommitInfo>, git2::Error>;
+    fn revert(&self, id: git2::Oid, message: &str) -> Result<git2::Oid, git2::Error>;
+    fn author(&self) -> Result<String, git2::Error>;
 }
 
 #[derive(Default)]
@@ -45,10 +67,18 @@ impl GitManagement for Git {
         repo.set_head(refname.as_str())
     }
 
-    fn add(&self) -> Result<(), git2::Error> {
-        let mut index = self.repo.as_ref().unwrap().index()?;
+    fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error> {
+        let repo = self.repo.as_ref().unwrap();
+        let mut index = repo.index()?;
 
-        index.add_path(Path::new("README.md"))?;
+        for path in paths {
+            // Paths that no longer exist are staged as deleted
+            if repo.workdir().is_some_and(|dir| dir.join(path).exists()) {
+                index.add_path(path)?;
+            } else {
+                index.remove_path(path)?;
+            }
+        }
         index.write()
     }
 
@@ -56,30 +86,29 @@ impl GitManagement for Git {
         let repo = self.repo.as_ref().unwrap();
         let mut index = repo.index()?;
 
-        let signature = repo.signature()?; // Use default user.name and user.email
-
         let oid = index.write_tree()?;
-        let parent_commit = find_last_commit(self.repo.as_ref().unwrap())?;
+        let parent_commit = find_last_commit(repo)?;
         let tree = repo.find_tree(oid)?;
 
-        repo.commit(
-            Some("HEAD"),      // point HEAD to our new commit
-            &signature,        // author
-            &signature,        // committer
-            subject,           // commit message
-            &tree,             // tree
-            &[&parent_commit], // parent commit
-        )
+        commit_tree(repo, subject, &tree, &parent_commit)
     }
 
-    fn push(&self, branch_name: &str) -> Result<(), git2::Error> {
+    fn push(&self, remote_name: &str, branch_name: &str) -> Result<(), git2::Error> {
         with_credentials(self.repo.as_ref().unwrap(), |cred_callback| {
-            let mut remote = self.repo.as_ref().unwrap().find_remote("origin")?;
+            let mut remote = self.repo.as_ref().unwrap().find_remote(remote_name)?;
 
             let mut callbacks = git2::RemoteCallbacks::new();
             let mut options = git2::PushOptions::new();
 
             callbacks.credentials(cred_callback);
+            // The push itself succeeds even if the remote rejects the update
+            callbacks.push_update_reference(|refname, status| match status {
+                Some(message) => Err(git2::Error::from_str(&format!(
+                    "Remote rejected {}: {}",
+                    refname, message
+                ))),
+                None => Ok(()),
+            });
             options.remote_callbacks(callbacks);
 
             remote.push(
@@ -93,9 +122,249 @@ impl GitManagement for Git {
             Ok(())
         })
     }
+
+    fn pull(&self, remote_name: &str, branch_name: &str) -> Result<(), git2::Error> {
+        let repo = self.repo.as_ref().unwrap();
+
+        with_credentials(repo, |cred_callback| {
+            let mut remote = repo.find_remote(remote_name)?;
+
+            let mut callbacks = git2::RemoteCallbacks::new();
+            let mut options = git2::FetchOptions::new();
+
+            callbacks.credentials(cred_callback);
+            options.remote_callbacks(callbacks);
+
+            remote.fetch(
+                &[format!(
+                    "refs/heads/{}:refs/remotes/{}/{}",
+                    branch_name, remote_name, branch_name
+                )],
+                Some(&mut options),
+                None,
+            )
+        })?;
+
+        let upstream_ref =
+            match repo.find_reference(&format!("refs/remotes/{}/{}", remote_name, branch_name)) {
+                Ok(reference) => reference,
+                // Nothing has been pushed to the remote branch yet
+                Err(err) if err.code() == git2::ErrorCode::NotFound => return Ok(()),
+                Err(err) => return Err(err),
+            };
+        let upstream = repo.reference_to_annotated_commit(&upstream_ref)?;
+
+        let (analysis, _) = repo.merge_analysis(&[&upstream])?;
+
+        if analysis.is_up_to_date() {
+            Ok(())
+        } else if analysis.is_fast_forward() || analysis.is_unborn() {
+            let refname = format!("refs/heads/{}", branch_name);
+            let target = repo.find_object(upstream.id(), None)?;
+            repo.checkout_tree(&target, Some(git2::build::CheckoutBuilder::new().safe()))?;
+            repo.reference(&refname, upstream.id(), true, "eureka: fast-forward")?;
+            repo.set_head(&refname)
+        } else {
+            rebase_onto(repo, &upstream)
+        }
+    }
+
+    fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+        let repo = self.repo.as_ref().unwrap();
+
+        match repo.head() {
+            Ok(head) if head.is_branch() => Ok(head.shorthand().map(String::from)),
+            Ok(_) => Ok(None),
+            // HEAD points to a branch without any commits yet
+            Err(err) if err.code() == git2::ErrorCode::UnbornBranch => {
+                let head = repo.find_reference("HEAD")?;
+                Ok(head
+                    .symbolic_target()
+                    .and_then(|target| target.strip_prefix("refs/heads/"))
+                    .map(String::from))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+        let remotes = self.repo.as_ref().unwrap().remotes()?;
+        Ok(remotes.iter().flatten().map(String::from).collect())
+    }
+
+    /// Find the commit that last changed each of the given lines of `path`,
+    /// `None` for lines that haven't been committed
+    fn blame_lines(
+        &self,
+        path: &Path,
+        lines: &[usize],
+    ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+        let repo = self.repo.as_ref().unwrap();
+
+        let blame = match repo.blame_file(path, None) {
+            Ok(blame) => blame,
+            // The file or the branch doesn't have any commits yet
+            Err(err)
+                if err.code() == git2::ErrorCode::NotFound
+                    || err.code() == git2::ErrorCode::UnbornBranch =>
+            {
+                return Ok(vec![None; lines.len()])
+            }
+            Err(err) => return Err(err),
+        };
+
+        lines
+            .iter()
+            .map(|line| match blame.get_line(*line) {
+                Some(hunk) => repo
+                    .find_commit(hunk.final_commit_id())
+                    .map(|commit| Some(commit_info(&commit))),
+                None => Ok(None),
+            })
+            .collect()
+    }
+
+    /// Every commit on the current branch, newest first
+    fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+        let repo = self.repo.as_ref().unwrap();
+
+        let mut revwalk = repo.revwalk()?;
+        match revwalk.push_head() {
+            Err(err) if err.code() == git2::ErrorCode::UnbornBranch => return Ok(vec![]),
+            result => result?,
+        }
+
+        revwalk
+            .map(|oid| repo.find_commit(oid?).map(|commit| commit_info(&commit)))
+            .collect()
+    }
+
+    /// Commit the inverse of commit `id` on top of HEAD and check it out.
+    /// Nothing is changed if the revert conflicts with later commits.
+    fn revert(&self, id: git2::Oid, message: &str) -> Result<git2::Oid, git2::Error> {
+        let repo = self.repo.as_ref().unwrap();
+        let commit = repo.find_commit(id)?;
+        let head_commit = find_last_commit(repo)?;
+
+        let mut index = repo.revert_commit(&commit, &head_commit, 0, None)?;
+        if index.has_conflicts() {
+            return Err(git2::Error::new(
+                git2::ErrorCode::Conflict,
+                git2::ErrorClass::Merge,
+                format!(
+                    "Commit {} conflicts with changes made after it",
+                    commit.id()
+                ),
+            ));
+        }
+
+        let tree = repo.find_tree(index.write_tree_to(repo)?)?;
+        repo.checkout_tree(
+            tree.as_object(),
+            Some(git2::build::CheckoutBuilder::new().safe()),
+        )?;
+
+        commit_tree(repo, message, &tree, &head_commit)
+    }
+
+    /// Name of the user commits are made as
+    fn author(&self) -> Result<String, git2::Error> {
+        let signature = self.repo.as_ref().unwrap().signature()?;
+        Ok(signature.name().unwrap_or_default().to_string())
+    }
+}
+
+/// Whether `err` means the remote couldn't be reached. A missing remote or
+/// rejected credentials are configuration problems and won't go away by
+/// retrying later.
+pub fn is_unreachable(err: &git2::Error) -> bool {
+    matches!(
+        err.class(),
+        git2::ErrorClass::Net
+            | git2::ErrorClass::Os
+            | git2::ErrorClass::Ssh
+            | git2::ErrorClass::Http
+    ) && !matches!(
+        err.code(),
+        git2::ErrorCode::Auth | git2::ErrorCode::Certificate
+    )
+}
+
+fn commit_tree(
+    repo: &git2::Repository,
+    message: &str,
+    tree: &git2::Tree,
+    parent_commit: &git2::Commit,
+) -> Result<git2::Oid, git2::Error> {
+    let signature = repo.signature()?; // Use default user.name and user.email
+
+    repo.commit(
+        Some("HEAD"),     // point HEAD to our new commit
+        &signature,       // author
+        &signature,       // committer
+        message,          // commit message
+        tree,             // tree
+        &[parent_commit], // parent commit
+    )
+}
+
+fn commit_info(commit: &git2::Commit) -> CommitInfo {
+    let time = commit.time();
+    let offset = FixedOffset::east_opt(time.offset_minutes() * 60)
+        .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+    CommitInfo {
+        id: commit.id(),
+        time: offset
+            .timestamp_opt(time.seconds(), 0)
+            .single()
+            .unwrap_or_default(),
+        summary: commit.summary().unwrap_or_default().to_string(),
+        message: commit.message().unwrap_or_default().to_string(),
+    }
 }
 
-fn find_last_commit(repo: &git2::Repository) -> Result<git2::Commit, git2::Error> {
+/// Replay the local commits on top of `upstream`. On conflicts the rebase is
+/// aborted, leaving the repo as it was, and a `Conflict` error listing
+/// the conflicting files is returned.
+fn rebase_onto(
+    repo: &git2::Repository,
+    upstream: &git2::AnnotatedCommit,
+) -> Result<(), git2::Error> {
+    let signature = repo.signature()?;
+    let mut rebase = repo.rebase(None, Some(upstream), None, None)?;
+
+    while let Some(operation) = rebase.next() {
+        operation?;
+
+        let index = repo.index()?;
+        if index.has_conflicts() {
+            let conflicting_paths = index
+                .conflicts()?
+                .flatten()
+                .filter_map(|conflict| conflict.our.or(conflict.their))
+                .map(|entry| String::from_utf8_lossy(&entry.path).into_owned())
+                .collect::<Vec<String>>();
+            rebase.abort()?;
+
+            return Err(git2::Error::new(
+                git2::ErrorCode::Conflict,
+                git2::ErrorClass::Merge,
+                format!("Conflicting changes in {}", conflicting_paths.join(", ")),
+            ));
+        }
+
+        match rebase.commit(None, &signature, None) {
+            // The change is already part of upstream, skip it
+            Err(err) if err.code() == git2::ErrorCode::Applied => {}
+            Err(err) => return Err(err),
+            Ok(_) => {}
+        }
+    }
+
+    rebase.finish(Some(&signature))
+}
+
+fn find_last_commit(repo: &git2::Repository) -> Result<git2::Commit<'_>, git2::Error> {
     let obj = repo.head()?.resolve()?.peel(git2::ObjectType::Commit)?;
     obj.into_commit()
         .map_err(|_| git2::Error::from_str("Couldn't find commit"))
@@ -144,8 +413,9 @@ where
 #[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
-    use crate::git::{find_last_commit, Git, GitManagement};
+    use crate::git::{find_last_commit, is_unreachable, Git, GitManagement};
     use git2::{BranchType, Repository, RepositoryInitOptions, Status};
+    use std::path::{Path, PathBuf};
     use tempfile::{NamedTempFile, TempDir};
 
     #[test]
@@ -211,13 +481,29 @@ mod tests {
         let before = statuses_before.get(0).unwrap();
         assert_eq!(before.status(), Status::WT_NEW);
 
-        git.add().unwrap();
+        git.add(&[PathBuf::from("README.md")]).unwrap();
 
         let statuses_after = repo.statuses(None).unwrap();
         let after = statuses_after.get(0).unwrap();
         assert_eq!(after.status(), Status::INDEX_NEW);
     }
 
+    #[test]
+    fn test_git__add__only_given_paths() {
+        let mut git = Git::default();
+        let (dir, repo, _file) = repo_init();
+        git.init(dir.path().to_str().unwrap()).unwrap();
+        std::fs::create_dir(dir.path().join("ideas")).unwrap();
+        std::fs::write(dir.path().join("ideas").join("idea.md"), "idea").unwrap();
+
+        git.add(&[PathBuf::from("ideas/idea.md")]).unwrap();
+
+        let idea_status = repo.status_file(Path::new("ideas/idea.md")).unwrap();
+        let readme_status = repo.status_file(Path::new("README.md")).unwrap();
+        assert_eq!(idea_status, Status::INDEX_NEW);
+        assert_eq!(readme_status, Status::WT_NEW);
+    }
+
     #[test]
     fn test_git__commit__success() {
         let mut git = Git::default();
@@ -228,13 +514,288 @@ mod tests {
         let before = find_last_commit(git.repo.as_ref().unwrap());
         assert_eq!(before.unwrap().summary().unwrap(), "initial-msg");
 
-        git.add().unwrap();
+        git.add(&[PathBuf::from("README.md")]).unwrap();
         git.commit("some-subject").unwrap();
 
         let after = find_last_commit(git.repo.as_ref().unwrap());
         assert_eq!(after.unwrap().summary().unwrap(), "some-subject");
     }
 
+    #[test]
+    fn test_git__add__deleted_path() {
+        let mut git = Git::default();
+        let (dir, repo, _file) = repo_init();
+        commit_file(&repo, "OTHER.md", "other idea\n", "other");
+        git.init(dir.path().to_str().unwrap()).unwrap();
+        std::fs::remove_file(dir.path().join("OTHER.md")).unwrap();
+
+        git.add(&[PathBuf::from("OTHER.md")]).unwrap();
+
+        let status = repo.status_file(Path::new("OTHER.md")).unwrap();
+        assert_eq!(status, Status::INDEX_DELETED);
+    }
+
+    #[test]
+    fn test_git__revert__success() {
+        let mut git = Git::default();
+        let (dir, repo, _file) = repo_init();
+        commit_file(&repo, "OTHER.md", "first idea\n", "first");
+        commit_file(&repo, "OTHER.md", "first idea\nsecond idea\n", "second");
+        commit_file(&repo, "THIRD.md", "third idea\n", "third");
+        git.init(dir.path().to_str().unwrap()).unwrap();
+        let second = git.log().unwrap()[1].id;
+
+        git.revert(second, "some-revert").unwrap();
+
+        let head = find_last_commit(&repo).unwrap();
+        assert_eq!(head.summary().unwrap(), "some-revert");
+        let contents = std::fs::read_to_string(dir.path().join("OTHER.md")).unwrap();
+        assert_eq!(contents, "first idea\n");
+        assert!(dir.path().join("THIRD.md").exists());
+        let status = repo.status_file(Path::new("OTHER.md")).unwrap();
+        assert_eq!(status, Status::CURRENT);
+    }
+
+    #[test]
+    fn test_git__revert__conflict() {
+        let mut git = Git::default();
+        let (dir, repo, _file) = repo_init();
+        commit_file(&repo, "OTHER.md", "first idea\n", "first");
+        commit_file(&repo, "OTHER.md", "changed idea\n", "second");
+        git.init(dir.path().to_str().unwrap()).unwrap();
+        let first = git.log().unwrap()[1].id;
+        let head_before = find_last_commit(&repo).unwrap().id();
+
+        let actual = git.revert(first, "some-revert").map_err(|err| err.code());
+
+        assert_eq!(actual, Err(git2::ErrorCode::Conflict));
+        let head_after = find_last_commit(&repo).unwrap().id();
+        assert_eq!(head_before, head_after);
+        let contents = std::fs::read_to_string(dir.path().join("OTHER.md")).unwrap();
+        assert_eq!(contents, "changed idea\n");
+    }
+
+    #[test]
+    fn test_git__current_branch__success() {
+        let mut git = Git::default();
+        let (dir, _repo, _file) = repo_init();
+        git.init(dir.path().to_str().unwrap()).unwrap();
+
+        let actual = git.current_branch().unwrap();
+
+        assert_eq!(actual, Some(String::from("main")));
+    }
+
+    #[test]
+    fn test_git__remotes__success() {
+        let mut git = Git::default();
+        let (dir, repo, _file) = repo_init();
+        git.init(dir.path().to_str().unwrap()).unwrap();
+
+        assert!(git.remotes().unwrap().is_empty());
+
+        repo.remote("upstream", "https://example.com/ideas.git")
+            .unwrap();
+
+        let actual = git.remotes().unwrap();
+
+        assert_eq!(actual, vec![String::from("upstream")]);
+    }
+
+    #[test]
+    fn test_git__blame_lines__success() {
+        let mut git = Git::default();
+        let (dir, repo, _file) = repo_init();
+        commit_file(&repo, "README.md", "# Ideas\n\n## First\n", "first-idea");
+        commit_file(
+            &repo,
+            "README.md",
+            "# Ideas\n\n## First\n\n## Second\n",
+            "second-idea",
+        );
+        git.init(dir.path().to_str().unwrap()).unwrap();
+
+        let actual = git
+            .blame_lines(Path::new("README.md"), &[3, 5, 42])
+            .unwrap();
+
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual[0].as_ref().unwrap().summary, "first-idea");
+        assert_eq!(actual[1].as_ref().unwrap().summary, "second-idea");
+        assert!(actual[2].is_none());
+    }
+
+    #[test]
+    fn test_git__blame_lines__uncommitted_file() {
+        let mut git = Git::default();
+        let (dir, _repo, _file) = repo_init();
+        git.init(dir.path().to_str().unwrap()).unwrap();
+
+        let actual = git.blame_lines(Path::new("README.md"), &[1]).unwrap();
+
+        assert_eq!(actual, vec![None]);
+    }
+
+    #[test]
+    fn test_git__log__success() {
+        let mut git = Git::default();
+        let (dir, repo, _file) = repo_init();
+        commit_file(&repo, "README.md", "# Ideas\n", "some-subject\n\nsome-body");
+        git.init(dir.path().to_str().unwrap()).unwrap();
+
+        let actual = git.log().unwrap();
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].summary, "some-subject");
+        assert_eq!(actual[0].message, "some-subject\n\nsome-body");
+        assert_eq!(actual[1].summary, "initial-msg");
+    }
+
+    #[test]
+    fn test_git__pull__fast_forward() {
+        let (_remote_dir, (dir_a, repo_a), (dir_b, repo_b)) = remote_init();
+        let mut git_a = Git::default();
+        git_a.init(dir_a.path().to_str().unwrap()).unwrap();
+        let mut git_b = Git::default();
+        git_b.init(dir_b.path().to_str().unwrap()).unwrap();
+
+        commit_file(&repo_a, "README.md", "first idea\nsecond idea\n", "second");
+        git_a.push("origin", "main").unwrap();
+
+        git_b.pull("origin", "main").unwrap();
+
+        let head_a = find_last_commit(&repo_a).unwrap().id();
+        let head_b = find_last_commit(&repo_b).unwrap().id();
+        assert_eq!(head_a, head_b);
+
+        let contents = std::fs::read_to_string(dir_b.path().join("README.md")).unwrap();
+        assert_eq!(contents, "first idea\nsecond idea\n");
+    }
+
+    #[test]
+    fn test_git__pull__rebases_local_commits() {
+        let (_remote_dir, (dir_a, repo_a), (dir_b, repo_b)) = remote_init();
+        let mut git_a = Git::default();
+        git_a.init(dir_a.path().to_str().unwrap()).unwrap();
+        let mut git_b = Git::default();
+        git_b.init(dir_b.path().to_str().unwrap()).unwrap();
+
+        commit_file(&repo_a, "README.md", "first idea\nsecond idea\n", "second");
+        git_a.push("origin", "main").unwrap();
+        commit_file(&repo_b, "OTHER.md", "other idea\n", "other");
+
+        git_b.pull("origin", "main").unwrap();
+
+        let head_a = find_last_commit(&repo_a).unwrap();
+        let head_b = find_last_commit(&repo_b).unwrap();
+        assert_eq!(head_b.summary().unwrap(), "other");
+        assert_eq!(head_b.parent_id(0).unwrap(), head_a.id());
+        assert!(dir_b.path().join("OTHER.md").exists());
+    }
+
+    #[test]
+    fn test_git__pull__conflict() {
+        let (_remote_dir, (dir_a, repo_a), (dir_b, repo_b)) = remote_init();
+        let mut git_a = Git::default();
+        git_a.init(dir_a.path().to_str().unwrap()).unwrap();
+        let mut git_b = Git::default();
+        git_b.init(dir_b.path().to_str().unwrap()).unwrap();
+
+        commit_file(&repo_a, "README.md", "idea from a\n", "from a");
+        git_a.push("origin", "main").unwrap();
+        commit_file(&repo_b, "README.md", "idea from b\n", "from b");
+        let head_before = find_last_commit(&repo_b).unwrap().id();
+
+        let actual = git_b.pull("origin", "main").map_err(|err| err.code());
+
+        assert_eq!(actual, Err(git2::ErrorCode::Conflict));
+        let head_after = find_last_commit(&repo_b).unwrap().id();
+        assert_eq!(head_before, head_after);
+    }
+
+    #[test]
+    fn test_git__pull__missing_remote() {
+        let (_remote_dir, _a, (dir_b, _repo_b)) = remote_init();
+        let mut git_b = Git::default();
+        git_b.init(dir_b.path().to_str().unwrap()).unwrap();
+
+        let actual = git_b.pull("some-missing-remote", "main").unwrap_err();
+
+        assert!(!is_unreachable(&actual));
+    }
+
+    #[test]
+    fn test_git__is_unreachable() {
+        let network_err = git2::Error::new(
+            git2::ErrorCode::GenericError,
+            git2::ErrorClass::Net,
+            "some-network-error",
+        );
+        let auth_err = git2::Error::new(
+            git2::ErrorCode::Auth,
+            git2::ErrorClass::Ssh,
+            "some-auth-error",
+        );
+        let certificate_err = git2::Error::new(
+            git2::ErrorCode::Certificate,
+            git2::ErrorClass::Ssh,
+            "some-host-key-error",
+        );
+
+        assert!(is_unreachable(&network_err));
+        assert!(!is_unreachable(&auth_err));
+        assert!(!is_unreachable(&certificate_err));
+        assert!(!is_unreachable(&git2::Error::from_str("some-error")));
+    }
+
+    /// Set up a bare remote with two clones of it, both on `main` with one
+    /// commit adding `README.md`.
+    fn remote_init() -> (TempDir, (TempDir, Repository), (TempDir, Repository)) {
+        let remote_dir = TempDir::new().unwrap();
+        let mut opts = RepositoryInitOptions::new();
+        opts.bare(true).initial_head("main");
+        Repository::init_opts(remote_dir.path(), &opts).unwrap();
+        let remote_url = remote_dir.path().to_str().unwrap();
+
+        let dir_a = TempDir::new().unwrap();
+        let repo_a = Repository::clone(remote_url, dir_a.path()).unwrap();
+        set_signature(&repo_a);
+        // Cloning an empty repo doesn't pick up its initial head
+        repo_a.set_head("refs/heads/main").unwrap();
+        commit_file(&repo_a, "README.md", "first idea\n", "first");
+        let mut git_a = Git::default();
+        git_a.init(dir_a.path().to_str().unwrap()).unwrap();
+        git_a.push("origin", "main").unwrap();
+
+        let dir_b = TempDir::new().unwrap();
+        let repo_b = Repository::clone(remote_url, dir_b.path()).unwrap();
+        set_signature(&repo_b);
+
+        (remote_dir, (dir_a, repo_a), (dir_b, repo_b))
+    }
+
+    fn set_signature(repo: &Repository) {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "some-name").unwrap();
+        config.set_str("user.email", "some-email").unwrap();
+    }
+
+    fn commit_file(repo: &Repository, file_name: &str, contents: &str, msg: &str) {
+        std::fs::write(repo.workdir().unwrap().join(file_name), contents).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new(file_name)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = repo.signature().unwrap();
+        let parents = match repo.head() {
+            Ok(head) => vec![head.peel_to_commit().unwrap()],
+            Err(_) => vec![],
+        };
+        let parents = parents.iter().collect::<Vec<_>>();
+        repo.commit(Some("HEAD"), &sig, &sig, msg, &tree, &parents)
+            .unwrap();
+    }
+
     fn repo_init() -> (TempDir, Repository, NamedTempFile) {
         let td = TempDir::new().unwrap();
         let mut opts = RepositoryInitOptions::new();
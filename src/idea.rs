@@ -0,0 +1,661 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+
+use crate::git::CommitInfo;
+
+const SECTION_PREFIX: &str = "## ";
+const META_START: &str = "<!-- eureka:";
+const META_END: &str = "-->";
+const FRONT_MATTER_DELIMITER: &str = "---";
+const DEFAULT_STATUS: &str = "new";
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IdeaFormat {
+    // A `## ` section in the single idea file, metadata in an HTML comment
+    Section,
+
+    // A file of its own with YAML front matter
+    FrontMatter,
+}
+
+/// Where an idea is in its lifecycle
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum IdeaStatus {
+    #[default]
+    New,
+    Exploring,
+    Doing,
+    Done,
+    Dropped,
+}
+
+impl IdeaStatus {
+    // In workflow order
+    pub const ALL: [IdeaStatus; 5] = [
+        IdeaStatus::New,
+        IdeaStatus::Exploring,
+        IdeaStatus::Doing,
+        IdeaStatus::Done,
+        IdeaStatus::Dropped,
+    ];
+}
+
+impl FromStr for IdeaStatus {
+    type Err = io::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        IdeaStatus::ALL
+            .into_iter()
+            .find(|status| status.to_string() == value)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Unknown status: {}, use new, exploring, doing, done or dropped",
+                        value
+                    ),
+                )
+            })
+    }
+}
+
+impl fmt::Display for IdeaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdeaStatus::New => write!(f, "new"),
+            IdeaStatus::Exploring => write!(f, "exploring"),
+            IdeaStatus::Doing => write!(f, "doing"),
+            IdeaStatus::Done => write!(f, "done"),
+            IdeaStatus::Dropped => write!(f, "dropped"),
+        }
+    }
+}
+
+/// A single idea. Parsed ideas remember the text they came from, so an idea
+/// that wasn't changed is written back exactly as it was read.
+#[derive(Debug, Clone)]
+pub struct Idea {
+    // Short id that doesn't change when other ideas are added or removed.
+    // Empty until assigned by `Storage`
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub created: Option<DateTime<FixedOffset>>,
+    pub tags: Vec<String>,
+    pub status: Option<String>,
+    pub author: Option<String>,
+    // The commit that added the idea, if it has been committed
+    pub commit: Option<CommitInfo>,
+    // File the idea lives in, relative to the repo root
+    pub path: PathBuf,
+    // Line the idea starts at, starting at 1
+    pub line: usize,
+    format: IdeaFormat,
+    // Metadata eureka doesn't know about, kept as is
+    extra: Vec<String>,
+    // The heading or front matter as read, and the values it held
+    raw_head: Option<(Head, String)>,
+    // Whitespace around the body as read
+    body_prefix: String,
+    body_suffix: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Head {
+    title: String,
+    created: Option<DateTime<FixedOffset>>,
+    tags: Vec<String>,
+    status: Option<String>,
+    author: Option<String>,
+    extra: Vec<String>,
+}
+
+impl Idea {
+    pub fn new(format: IdeaFormat, path: &Path, title: &str) -> Self {
+        Idea {
+            id: String::new(),
+            title: title.to_string(),
+            body: String::new(),
+            created: None,
+            tags: vec![],
+            status: None,
+            author: None,
+            commit: None,
+            path: path.to_path_buf(),
+            line: 1,
+            format,
+            extra: vec![],
+            raw_head: None,
+            body_prefix: String::new(),
+            body_suffix: String::new(),
+        }
+    }
+
+    /// Parse the first idea in `text`, written in the given format
+    pub fn parse(format: IdeaFormat, path: &Path, text: &str) -> Option<Self> {
+        match format {
+            IdeaFormat::Section => IdeaDocument::parse_single_file(path, text)
+                .ideas
+                .into_iter()
+                .next(),
+            IdeaFormat::FrontMatter => Some(Idea::parse_file(path, text)),
+        }
+    }
+
+    pub fn format(&self) -> IdeaFormat {
+        self.format
+    }
+
+    /// When the idea was created, falling back to when it was committed
+    pub fn date(&self) -> Option<DateTime<FixedOffset>> {
+        self.created
+            .or_else(|| self.commit.as_ref().map(|commit| commit.time))
+    }
+
+    /// The idea's status, ideas without one are new
+    pub fn status(&self) -> &str {
+        self.status.as_deref().unwrap_or(DEFAULT_STATUS)
+    }
+
+    pub fn set_body(&mut self, body: &str) {
+        self.body = body.trim().to_string();
+        if self.body.is_empty() {
+            return;
+        }
+        if !self.body_prefix.contains('\n') {
+            self.body_prefix = String::from("\n");
+        }
+        if !self.body_suffix.contains('\n') {
+            self.body_suffix = String::from("\n");
+        }
+    }
+
+    fn head(&self) -> Head {
+        Head {
+            title: self.title.clone(),
+            created: self.created,
+            tags: self.tags.clone(),
+            status: self.status.clone(),
+            author: self.author.clone(),
+            extra: self.extra.clone(),
+        }
+    }
+
+    fn parse_section(path: &Path, line: usize, text: &str) -> Self {
+        let mut lines = text.split_inclusive('\n');
+        let heading = lines.next().unwrap_or_default();
+        let mut head_len = heading.len();
+        let mut idea = Idea::new(
+            IdeaFormat::Section,
+            path,
+            heading[SECTION_PREFIX.len()..].trim(),
+        );
+        idea.line = line;
+
+        if let Some(meta) = lines.next().and_then(|next| {
+            let fields = next
+                .trim()
+                .strip_prefix(META_START)?
+                .strip_suffix(META_END)?;
+            head_len += next.len();
+            Some(fields)
+        }) {
+            for field in meta.split(';').map(str::trim).filter(|f| !f.is_empty()) {
+                match field.split_once('=') {
+                    Some(("created", value)) => match parse_date(value.trim()) {
+                        Some(created) => idea.created = Some(created),
+                        None => idea.extra.push(field.to_string()),
+                    },
+                    Some(("tags", value)) => {
+                        idea.tags = value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|tag| !tag.is_empty())
+                            .map(String::from)
+                            .collect()
+                    }
+                    Some(("status", value)) => idea.status = Some(value.trim().to_string()),
+                    Some(("author", value)) => idea.author = Some(value.trim().to_string()),
+                    _ => idea.extra.push(field.to_string()),
+                }
+            }
+        }
+
+        idea.raw_head = Some((idea.head(), text[..head_len].to_string()));
+        idea.split_body(&text[head_len..]);
+        idea
+    }
+
+    fn parse_file(path: &Path, contents: &str) -> Self {
+        let title = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut idea = Idea::new(IdeaFormat::FrontMatter, path, &title);
+
+        let mut lines = contents.split_inclusive('\n');
+        let mut head_len = 0;
+        let mut entries: Vec<String> = vec![];
+        if let Some(first) = lines
+            .next()
+            .filter(|line| line.trim_end() == FRONT_MATTER_DELIMITER)
+        {
+            let mut len = first.len();
+            for line in lines {
+                len += line.len();
+                if line.trim_end() == FRONT_MATTER_DELIMITER {
+                    head_len = len;
+                    break;
+                }
+                match entries.last_mut() {
+                    // Indented lines and block list items belong to the previous key
+                    Some(entry) if line.starts_with([' ', '\t', '-']) => entry.push_str(line),
+                    _ => entries.push(line.to_string()),
+                }
+            }
+        }
+        if head_len == 0 {
+            entries.clear();
+        }
+
+        for entry in entries {
+            let (key, value) = entry.split_once(':').unwrap_or((&entry, ""));
+            match key.trim() {
+                "title" => idea.title = unquote(value.trim()),
+                "created" => match parse_date(&unquote(value.trim())) {
+                    Some(created) => idea.created = Some(created),
+                    None => idea.extra.push(entry.clone()),
+                },
+                "tags" => idea.tags = parse_list(value),
+                "status" => idea.status = Some(unquote(value.trim())),
+                "author" => idea.author = Some(unquote(value.trim())),
+                _ => idea.extra.push(entry.clone()),
+            }
+        }
+
+        idea.raw_head = Some((idea.head(), contents[..head_len].to_string()));
+        idea.split_body(&contents[head_len..]);
+        idea
+    }
+
+    fn split_body(&mut self, text: &str) {
+        let body_start = text
+            .split_inclusive('\n')
+            .take_while(|line| line.trim().is_empty())
+            .map(str::len)
+            .sum::<usize>();
+        let body = text[body_start..].trim_end();
+
+        self.body_prefix = text[..body_start].to_string();
+        self.body = body.to_string();
+        self.body_suffix = text[body_start + body.len()..].to_string();
+    }
+
+    fn write_head(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((head, raw)) = &self.raw_head {
+            if *head == self.head() {
+                return write!(f, "{}", raw);
+            }
+        }
+
+        match self.format {
+            IdeaFormat::Section => {
+                writeln!(f, "{}{}", SECTION_PREFIX, self.title)?;
+                let mut fields = vec![];
+                if let Some(created) = self.created {
+                    fields.push(format!("created={}", format_date(created)));
+                }
+                if !self.tags.is_empty() {
+                    fields.push(format!("tags={}", self.tags.join(",")));
+                }
+                if let Some(status) = &self.status {
+                    fields.push(format!("status={}", status));
+                }
+                if let Some(author) = &self.author {
+                    fields.push(format!("author={}", author));
+                }
+                fields.extend(self.extra.iter().cloned());
+                if !fields.is_empty() {
+                    writeln!(f, "{} {} {}", META_START, fields.join("; "), META_END)?;
+                }
+                Ok(())
+            }
+            IdeaFormat::FrontMatter => {
+                writeln!(f, "{}", FRONT_MATTER_DELIMITER)?;
+                // A JSON string is a valid double quoted YAML string
+                writeln!(f, "title: {}", quote(&self.title))?;
+                if let Some(created) = self.created {
+                    writeln!(f, "created: {}", format_date(created))?;
+                }
+                let tags = self
+                    .tags
+                    .iter()
+                    .map(|tag| quote_plain(tag))
+                    .collect::<Vec<_>>();
+                writeln!(f, "tags: [{}]", tags.join(", "))?;
+                if let Some(status) = &self.status {
+                    writeln!(f, "status: {}", quote_plain(status))?;
+                }
+                if let Some(author) = &self.author {
+                    writeln!(f, "author: {}", quote_plain(author))?;
+                }
+                for entry in &self.extra {
+                    write!(f, "{}", entry)?;
+                }
+                writeln!(f, "{}", FRONT_MATTER_DELIMITER)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Idea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_head(f)?;
+        write!(f, "{}{}{}", self.body_prefix, self.body, self.body_suffix)
+    }
+}
+
+/// A file in the idea repo and the ideas in it. Everything that isn't part
+/// of an idea, like the heading of README.md, is kept as is.
+#[derive(Debug, Clone)]
+pub struct IdeaDocument {
+    pub path: PathBuf,
+    pub ideas: Vec<Idea>,
+    preamble: String,
+}
+
+impl IdeaDocument {
+    /// Parse a document where every `## ` heading starts a new idea
+    pub fn parse_single_file(path: &Path, contents: &str) -> Self {
+        let mut preamble_len = contents.len();
+        let mut starts = vec![];
+        let mut offset = 0;
+        let mut is_in_code_block = false;
+
+        for (line_index, line) in contents.split_inclusive('\n').enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                is_in_code_block = !is_in_code_block;
+            } else if !is_in_code_block && line.starts_with(SECTION_PREFIX) {
+                if starts.is_empty() {
+                    preamble_len = offset;
+                }
+                starts.push((offset, line_index + 1));
+            }
+            offset += line.len();
+        }
+
+        let ideas = starts
+            .iter()
+            .enumerate()
+            .map(|(i, (start, line))| {
+                let end = starts.get(i + 1).map_or(contents.len(), |next| next.0);
+                Idea::parse_section(path, *line, &contents[*start..end])
+            })
+            .collect();
+
+        IdeaDocument {
+            path: path.to_path_buf(),
+            ideas,
+            preamble: contents[..preamble_len].to_string(),
+        }
+    }
+
+    /// Parse a document that holds a single idea with front matter
+    pub fn parse_idea_file(path: &Path, contents: &str) -> Self {
+        IdeaDocument {
+            path: path.to_path_buf(),
+            ideas: vec![Idea::parse_file(path, contents)],
+            preamble: String::new(),
+        }
+    }
+}
+
+impl IdeaDocument {
+    /// Replace the idea starting at `line` with `text`. The text may hold
+    /// any number of ideas, or none to remove the idea. It's followed by
+    /// exactly one blank line if another idea comes after it.
+    pub fn replace_idea(&mut self, line: usize, text: &str) -> bool {
+        let index = match self.ideas.iter().position(|idea| idea.line == line) {
+            Some(index) => index,
+            None => return false,
+        };
+        let is_last = index + 1 == self.ideas.len();
+
+        let mut contents = self.preamble.clone();
+        for (i, idea) in self.ideas.iter().enumerate() {
+            if i != index {
+                contents.push_str(&idea.to_string());
+                continue;
+            }
+            let text = text.trim_end();
+            if text.is_empty() {
+                continue;
+            }
+            contents.push_str(text);
+            contents.push_str(if is_last { "\n" } else { "\n\n" });
+        }
+
+        *self = match self.ideas[0].format {
+            IdeaFormat::Section => IdeaDocument::parse_single_file(&self.path, &contents),
+            IdeaFormat::FrontMatter => IdeaDocument::parse_idea_file(&self.path, &contents),
+        };
+        true
+    }
+}
+
+impl fmt::Display for IdeaDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.preamble)?;
+        for idea in &self.ideas {
+            write!(f, "{}", idea)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn format_date(date: DateTime<FixedOffset>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+fn parse_date(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value).ok()
+}
+
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+/// Quote a YAML value only when it isn't a plain word
+fn quote_plain(value: &str) -> String {
+    if !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        value.to_string()
+    } else {
+        quote(value)
+    }
+}
+
+fn unquote(value: &str) -> String {
+    if value.starts_with('"') {
+        serde_json::from_str(value).unwrap_or_else(|_| value.to_string())
+    } else if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        value[1..value.len() - 1].replace("''", "'")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parse a YAML list written either as `[a, b]` or as `- a` lines
+fn parse_list(value: &str) -> Vec<String> {
+    let value = value.trim();
+    let items: Vec<&str> = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(flow) => flow.split(',').collect(),
+        None => value
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix('-'))
+            .collect(),
+    };
+    items
+        .into_iter()
+        .map(|item| unquote(item.trim()))
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use crate::idea::{Idea, IdeaDocument, IdeaFormat, IdeaStatus};
+    use chrono::DateTime;
+    use std::path::Path;
+
+    const SINGLE_FILE: &str = "# Ideas\n\nSome intro\n\n## First idea\n\nFirst body\n\n\
+                               ## Second idea\n<!-- eureka: created=2026-10-18T09:30:00+02:00; tags=rust, cli; status=doing; votes=3 -->\n\n\
+                               ```sh\n## not a heading\n```\n\n## Third idea";
+
+    const IDEA_FILE: &str = "---\ntitle: \"A \\\"quoted\\\" idea\"\ncreated: 2026-10-18T09:30:00+02:00\n\
+                             tags:\n  - rust\n  - 'cli'\nstatus: new\nauthor: someone\n---\n\nThe body\n";
+
+    #[test]
+    fn test_idea__parse_single_file() {
+        let doc = IdeaDocument::parse_single_file(Path::new("README.md"), SINGLE_FILE);
+
+        assert_eq!(doc.ideas.len(), 3);
+        let first = &doc.ideas[0];
+        assert_eq!(first.title, "First idea");
+        assert_eq!(first.body, "First body");
+        assert_eq!(first.line, 5);
+        assert_eq!(first.created, None);
+
+        let second = &doc.ideas[1];
+        assert_eq!(second.title, "Second idea");
+        assert_eq!(second.body, "```sh\n## not a heading\n```");
+        assert_eq!(
+            second.created,
+            Some(DateTime::parse_from_rfc3339("2026-10-18T09:30:00+02:00").unwrap())
+        );
+        assert_eq!(second.tags, vec!["rust", "cli"]);
+        assert_eq!(second.status.as_deref(), Some("doing"));
+
+        assert_eq!(doc.ideas[2].title, "Third idea");
+        assert_eq!(doc.ideas[2].body, "");
+    }
+
+    #[test]
+    fn test_idea__parse_single_file__lossless() {
+        let doc = IdeaDocument::parse_single_file(Path::new("README.md"), SINGLE_FILE);
+
+        assert_eq!(doc.to_string(), SINGLE_FILE);
+    }
+
+    #[test]
+    fn test_idea__parse_single_file__changed_idea() {
+        let mut doc = IdeaDocument::parse_single_file(Path::new("README.md"), SINGLE_FILE);
+
+        doc.ideas[0].tags.push(String::from("new-tag"));
+        doc.ideas[1].title = String::from("Renamed idea");
+
+        assert_eq!(
+            doc.to_string(),
+            "# Ideas\n\nSome intro\n\n## First idea\n<!-- eureka: tags=new-tag -->\n\nFirst body\n\n\
+             ## Renamed idea\n<!-- eureka: created=2026-10-18T09:30:00+02:00; tags=rust,cli; status=doing; votes=3 -->\n\n\
+             ```sh\n## not a heading\n```\n\n## Third idea"
+        );
+    }
+
+    #[test]
+    fn test_idea__parse_idea_file() {
+        let doc = IdeaDocument::parse_idea_file(Path::new("ideas/idea.md"), IDEA_FILE);
+
+        let idea = &doc.ideas[0];
+        assert_eq!(idea.format(), IdeaFormat::FrontMatter);
+        assert_eq!(idea.title, "A \"quoted\" idea");
+        assert_eq!(idea.body, "The body");
+        assert_eq!(idea.tags, vec!["rust", "cli"]);
+        assert_eq!(idea.status.as_deref(), Some("new"));
+        assert_eq!(idea.author.as_deref(), Some("someone"));
+        assert_eq!(doc.to_string(), IDEA_FILE);
+    }
+
+    #[test]
+    fn test_idea__parse_idea_file__changed_idea() {
+        let mut doc = IdeaDocument::parse_idea_file(Path::new("ideas/idea.md"), IDEA_FILE);
+
+        doc.ideas[0].status = Some(String::from("done"));
+
+        assert_eq!(
+            doc.to_string(),
+            "---\ntitle: \"A \\\"quoted\\\" idea\"\ncreated: 2026-10-18T09:30:00+02:00\n\
+             tags: [rust, cli]\nstatus: done\nauthor: someone\n---\n\nThe body\n"
+        );
+    }
+
+    #[test]
+    fn test_idea__parse_idea_file__without_front_matter() {
+        let doc = IdeaDocument::parse_idea_file(Path::new("ideas/plain.md"), "Just text\n");
+
+        let idea = &doc.ideas[0];
+        assert_eq!(idea.title, "plain");
+        assert_eq!(idea.body, "Just text");
+        assert_eq!(doc.to_string(), "Just text\n");
+    }
+
+    #[test]
+    fn test_idea__replace_idea() {
+        let mut doc = IdeaDocument::parse_single_file(Path::new("README.md"), SINGLE_FILE);
+
+        let is_replaced = doc.replace_idea(5, "## Edited idea\n\nEdited body");
+
+        assert!(is_replaced);
+        assert_eq!(doc.ideas.len(), 3);
+        assert_eq!(doc.ideas[0].title, "Edited idea");
+        assert_eq!(doc.ideas[1].title, "Second idea");
+        assert!(doc.to_string().starts_with(
+            "# Ideas\n\nSome intro\n\n## Edited idea\n\nEdited body\n\n## Second idea\n"
+        ));
+        assert!(!doc.replace_idea(42, ""));
+    }
+
+    #[test]
+    fn test_idea__replace_idea__normalizes_blank_lines() {
+        let mut doc = IdeaDocument::parse_single_file(Path::new("README.md"), SINGLE_FILE);
+
+        doc.replace_idea(5, "## Edited idea\n\nEdited body\n\n\n\n");
+        let second_line = doc.ideas[1].line;
+        doc.replace_idea(second_line, "## Second idea\n\nNo trailing newline");
+
+        assert!(doc.to_string().starts_with(
+            "# Ideas\n\nSome intro\n\n## Edited idea\n\nEdited body\n\n\
+             ## Second idea\n\nNo trailing newline\n\n## Third idea"
+        ));
+    }
+
+    #[test]
+    fn test_idea__new() {
+        let mut idea = Idea::new(IdeaFormat::FrontMatter, Path::new("ideas/idea.md"), "Idea");
+        idea.status = Some(String::from("new"));
+        idea.set_body("  The body\n\n");
+
+        assert_eq!(
+            idea.to_string(),
+            "---\ntitle: \"Idea\"\ntags: []\nstatus: new\n---\n\nThe body\n"
+        );
+    }
+
+    #[test]
+    fn test_idea__status__from_str() {
+        for status in IdeaStatus::ALL {
+            assert_eq!(status.to_string().parse::<IdeaStatus>().unwrap(), status);
+        }
+        assert_eq!("doing".parse::<IdeaStatus>().unwrap(), IdeaStatus::Doing);
+        assert!("blocked".parse::<IdeaStatus>().is_err());
+    }
+}
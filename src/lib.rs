@@ -3,22 +3,61 @@ extern crate dirs;
 extern crate log;
 extern crate core;
 
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fs;
 use std::io;
 use std::io::{Error, ErrorKind};
+use std::iter;
+use std::ops::Range;
 
 use crate::config_manager::ConfigManagement;
-use crate::config_manager::ConfigType::Repo;
-use crate::git::GitManagement;
+use crate::config_manager::ConfigType::{
+    Branch, Editor, File as IdeaFileConfig, Pager, Position, Remote, Repo,
+    Storage as StorageConfig, Template,
+};
+use crate::config_manager::{ConfigOrigin, ConfigType, Profile, Profiles};
+use crate::git::{is_unreachable, CommitInfo, GitManagement};
+use crate::idea::{Idea, IdeaStatus};
 use crate::printer::{Print, PrintColor};
-use crate::program_access::ProgramOpener;
+use crate::program_access::{find_program, ProgramOpener};
 use crate::reader::ReadInput;
-use std::path::Path;
+use crate::storage::{IdeaPosition, Storage, StorageLayout, IDEA_FILE_NAME};
+use crate::template::{render, template_path, TemplateVars, REPO_TEMPLATES_DIR};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate};
+use regex::{Regex, RegexBuilder};
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
+use termcolor::{Ansi, NoColor};
 
+pub mod browse;
 pub mod config_manager;
 pub mod git;
+pub mod idea;
+pub mod markdown;
 pub mod printer;
 pub mod program_access;
 pub mod reader;
+pub mod storage;
+pub mod template;
+
+const DEFAULT_BRANCH: &str = "main";
+const DEFAULT_REMOTE: &str = "origin";
+const SHORT_COMMIT_ID_LEN: usize = 7;
+const NEW_IDEA: &str = "your new idea";
+const MAX_SUGGESTED_TAGS: usize = 5;
+// Same wording as `git revert`, so undos are recognized by both
+const REVERTS_COMMIT: &str = "This reverts commit ";
+// What the subjects of commits eureka makes start with. New ideas are
+// committed with just their summary instead
+const COMMIT_PREFIXES: [&str; 6] = [
+    "Edit: ",
+    "Remove: ",
+    "Archive: ",
+    "Status: ",
+    "Tags: ",
+    "Undo: ",
+];
 
 pub struct Eureka<
     CM: ConfigManagement,
@@ -35,12 +74,189 @@ pub struct Eureka<
 }
 
 #[derive(Debug)]
-pub struct EurekaOptions {
-    // Clear the stored config
-    pub clear_config: bool,
+pub enum EurekaCommand {
+    // Capture a new idea, running first time setup if needed
+    Add(AddOptions),
 
     // Open idea document with $PAGER (fall back to `less`)
-    pub view: bool,
+    View(ViewOptions),
+
+    // Manage the stored config
+    Config(ConfigCommand),
+
+    // Push ideas that were committed while offline
+    Sync,
+
+    // Print one line per idea
+    List(ListOptions),
+
+    // Find ideas, and optionally commits, matching a query
+    Search(SearchOptions),
+
+    // Print every tag along with how many ideas have it
+    Tags,
+
+    // Edit the idea with the given id, or the one whose title matches
+    Edit(String),
+
+    // Move the idea with the given id, or the one whose title matches, to a new status
+    Status(String, IdeaStatus),
+
+    // Replace the tags of the idea with the given id, or the one whose title matches
+    Tag(String, Vec<String>),
+
+    // Delete the idea with the given id, or the one whose title matches
+    Remove(String),
+
+    // Move the idea with the given id, or the one whose title matches, to the archive
+    Archive(String),
+
+    // Revert the most recent commit made by eureka
+    Undo,
+
+    // Check that the config and idea repo are set up correctly
+    Doctor,
+
+    // Full screen list of ideas with a preview, filtered as you type
+    Browse,
+}
+
+#[derive(Debug, Default)]
+pub struct AddOptions {
+    // Idea summary given up front, skips the summary prompt and $EDITOR
+    pub message: Option<String>,
+
+    // Idea body given up front, skips $EDITOR
+    pub body: Option<String>,
+
+    // Don't pull or push, only commit the idea locally
+    pub offline: bool,
+
+    // Name of the template to start the idea body from
+    pub template: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ListOptions {
+    // Only ideas created on or after this date
+    pub since: Option<NaiveDate>,
+
+    // Only ideas created on or before this date
+    pub until: Option<NaiveDate>,
+
+    // Only ideas with all of these tags
+    pub tags: Vec<String>,
+
+    // Only ideas with this status
+    pub status: Option<IdeaStatus>,
+
+    // Print at most this many ideas
+    pub limit: Option<usize>,
+
+    pub sort: SortBy,
+
+    // Reverse the sort order
+    pub reverse: bool,
+
+    // Print the ideas grouped by status
+    pub group: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum SortBy {
+    // Newest first
+    #[default]
+    Date,
+
+    // Alphabetically, ignoring case
+    Title,
+}
+
+impl ListOptions {
+    fn matches(&self, idea: &Idea) -> bool {
+        let date = idea.date().map(|date| date.date_naive());
+        let is_after_since = self
+            .since
+            .is_none_or(|since| date.is_some_and(|date| date >= since));
+        let is_before_until = self
+            .until
+            .is_none_or(|until| date.is_some_and(|date| date <= until));
+        let has_tags = self.tags.iter().all(|tag| idea.tags.contains(tag));
+        let has_status = self
+            .status
+            .is_none_or(|status| idea.status() == status.to_string());
+
+        is_after_since && is_before_until && has_tags && has_status
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ViewOptions {
+    // Only ideas with this status
+    pub status: Option<IdeaStatus>,
+
+    // Show the ideas grouped by status
+    pub group: bool,
+
+    // Show the Markdown as is instead of rendering it
+    pub raw: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct SearchOptions {
+    // Text or regex to search for
+    pub query: String,
+
+    // Ignore case when matching
+    pub ignore_case: bool,
+
+    // Treat the query as a regex
+    pub regex: bool,
+
+    // Also search the commit messages of the idea repo
+    pub commits: bool,
+}
+
+#[derive(Debug)]
+pub enum ConfigCommand {
+    // Clear the stored config
+    Clear,
+
+    // Manage the profiles, each with its own idea repo
+    Profiles(ProfileCommand),
+
+    // Print every setting in effect, optionally with where it comes from
+    Show { origin: bool },
+
+    // Print the value in effect of a setting
+    Get(ConfigType),
+
+    // Store a setting, once it's checked to make sense
+    Set(ConfigType, String),
+
+    // Remove a stored setting
+    Unset(ConfigType),
+
+    // Print every setting stored in the config
+    List,
+
+    // Edit the config in the editor, checking it before it's saved
+    Edit,
+}
+
+#[derive(Debug)]
+pub enum ProfileCommand {
+    // Print every profile, marking the default one
+    List,
+
+    // Add a profile with the given name
+    Add(String, Profile),
+
+    // Remove the profile with the given name
+    Remove(String),
+
+    // Use the profile with the given name when none is picked
+    Default(String),
 }
 
 impl<CM, W, R, G, PO> Eureka<CM, W, R, G, PO>
@@ -61,96 +277,1107 @@ where
         }
     }
 
-    pub fn run(&mut self, opts: EurekaOptions) -> io::Result<()> {
-        debug!("Running with options: {:?}", &opts);
+    pub fn run(&mut self, command: EurekaCommand) -> io::Result<()> {
+        debug!("Running command: {:?}", &command);
+
+        match command {
+            EurekaCommand::Add(opts) => self.add(opts),
+            EurekaCommand::View(opts) => self.view(opts),
+            EurekaCommand::Config(ConfigCommand::Clear) => {
+                self.clear_config()?;
+                debug!("Cleared config");
+                Ok(())
+            }
+            EurekaCommand::Config(ConfigCommand::Profiles(command)) => self.profiles(command),
+            EurekaCommand::Config(ConfigCommand::Show { origin }) => self.show_config(origin),
+            EurekaCommand::Config(ConfigCommand::Get(config_type)) => self.get_config(config_type),
+            EurekaCommand::Config(ConfigCommand::Set(config_type, value)) => {
+                self.set_config(config_type, value)
+            }
+            EurekaCommand::Config(ConfigCommand::Unset(config_type)) => {
+                self.unset_config(config_type)
+            }
+            EurekaCommand::Config(ConfigCommand::List) => self.list_config(),
+            EurekaCommand::Config(ConfigCommand::Edit) => self.edit_config(),
+            EurekaCommand::Sync => self.sync(),
+            EurekaCommand::List(opts) => self.list(opts),
+            EurekaCommand::Search(opts) => self.search(opts),
+            EurekaCommand::Tags => self.tags(),
+            EurekaCommand::Edit(target) => self.edit(&target),
+            EurekaCommand::Status(target, status) => self.set_status(&target, status),
+            EurekaCommand::Tag(target, tags) => self.set_tags(&target, &tags),
+            EurekaCommand::Remove(target) => self.remove(&target, false),
+            EurekaCommand::Archive(target) => self.remove(&target, true),
+            EurekaCommand::Undo => self.undo(),
+            EurekaCommand::Doctor => self.doctor(),
+            EurekaCommand::Browse => self.browse(),
+        }
+    }
+
+    /// Read every idea in the idea repo along with the commit that added it
+    pub fn ideas(&mut self) -> io::Result<Vec<Idea>> {
+        let repo_path = self.cm.config_read(Repo)?;
+        self.git
+            .init(&repo_path)
+            .map_err(|git_err| Error::new(ErrorKind::InvalidInput, git_err))?;
+
+        let mut ideas = self.storage(&repo_path)?.ideas()?;
+
+        // Blame each file once for all the ideas in it
+        let mut start = 0;
+        while start < ideas.len() {
+            let path = ideas[start].path.clone();
+            let end = start
+                + ideas[start..]
+                    .iter()
+                    .take_while(|idea| idea.path == path)
+                    .count();
+            let lines = ideas[start..end]
+                .iter()
+                .map(|idea| idea.line)
+                .collect::<Vec<usize>>();
 
-        if opts.clear_config {
-            self.clear_config()?;
-            debug!("Cleared config");
-            return Ok(());
+            let commits = self
+                .git
+                .blame_lines(&path, &lines)
+                .map_err(io::Error::other)?;
+            for (idea, commit) in ideas[start..end].iter_mut().zip(commits) {
+                idea.commit = commit;
+            }
+            start = end;
         }
 
-        if opts.view {
-            self.open_idea_file()?;
-            return Ok(());
+        Ok(ideas)
+    }
+
+    fn add(&mut self, opts: AddOptions) -> io::Result<()> {
+        if opts.message.is_some() || opts.body.is_some() {
+            return self.capture_idea(opts);
         }
 
         if self.is_config_missing() {
             debug!("Config is missing");
+            self.first_time_setup()
+        } else {
+            self.ask_for_idea(opts)
+        }
+    }
 
-            // If config dir is missing - create it
-            if !self.cm.config_dir_exists() {
-                self.cm.config_dir_create()?;
-                debug!("Created config dir");
-            }
+    fn first_time_setup(&mut self) -> io::Result<()> {
+        // If config dir is missing - create it
+        if !self.cm.config_dir_exists() {
+            self.cm.config_dir_create()?;
+            debug!("Created config dir");
+        }
 
-            self.printer.fts_banner()?;
+        self.printer.fts_banner()?;
 
-            // If repo path is missing - ask for it
-            if self.cm.config_read(Repo).is_err() {
-                self.setup_repo_path()?;
+        // If repo path is missing - ask for it
+        let repo_path = match self.cm.config_read(Repo) {
+            Ok(repo_path) => repo_path,
+            Err(_) => {
+                let repo_path = self.setup_repo_path()?;
                 debug!("Setup repo path successfully");
+                repo_path
             }
+        };
 
-            self.printer
-                .println("First time setup complete. Happy ideation!")?;
-            Ok(())
-        } else {
-            self.ask_for_idea()
+        // If branch or remote is missing - ask for them, suggesting what the repo uses
+        let is_branch_missing = self.cm.config_read(Branch).is_err();
+        let is_remote_missing = self.cm.config_read(Remote).is_err();
+        if is_branch_missing || is_remote_missing {
+            let (default_branch, default_remote) = self.detect_branch_and_remote(&repo_path);
+
+            if is_branch_missing {
+                let branch = self.ask_with_default("Name of branch", &default_branch)?;
+                self.cm.config_write(Branch, branch)?;
+            }
+
+            if is_remote_missing {
+                let remote = self.ask_with_default("Name of remote", &default_remote)?;
+                self.cm.config_write(Remote, remote)?;
+            }
+            debug!("Setup branch and remote successfully");
+        }
+
+        // If storage layout is missing - ask for it
+        if self.cm.config_read(StorageConfig).is_err() {
+            let layout = self.setup_storage_layout()?;
+            self.cm.config_write(StorageConfig, layout.to_string())?;
+            debug!("Setup storage layout successfully");
         }
+
+        // If editor or pager is missing - ask for them, both are optional
+        if self.cm.config_read(Editor).is_err() {
+            if let Some(editor) =
+                self.setup_program("Editor, leave empty to use $VISUAL or $EDITOR")?
+            {
+                self.cm.config_write(Editor, editor)?;
+            }
+        }
+        if self.cm.config_read(Pager).is_err() {
+            if let Some(pager) = self.setup_program("Pager, leave empty to use $PAGER")? {
+                self.cm.config_write(Pager, pager)?;
+            }
+        }
+
+        self.printer
+            .println("First time setup complete. Happy ideation!")
     }
 
-    fn ask_for_idea(&mut self) -> io::Result<()> {
+    /// Ask whether a tag that isn't used yet was meant to be one of the
+    /// `similar` tags that are. It's kept as typed unless one of them is picked.
+    fn confirm_new_tag(&mut self, tag: &str, similar: &[&String]) -> io::Result<String> {
+        self.printer.input_header(&format!(
+            "#{} is a new tag, did you mean {}? Enter the tag to use, or leave empty to keep #{}",
+            tag,
+            similar
+                .iter()
+                .map(|tag| format!("#{}", tag))
+                .collect::<Vec<_>>()
+                .join(" or "),
+            tag
+        ))?;
+        let answer = self.reader.read_input()?;
+        let answer = answer.trim_start_matches('#');
+        Ok(similar
+            .iter()
+            .find(|similar| similar.as_str() == answer)
+            .map_or_else(|| tag.to_string(), |similar| similar.to_string()))
+    }
+
+    fn ask_for_idea(&mut self, opts: AddOptions) -> io::Result<()> {
+        // Suggest the tags already in use so the same ones keep being used
+        let known_tags = self
+            .cm
+            .config_read(Repo)
+            .and_then(|repo_path| self.storage(&repo_path)?.ideas())
+            .map(|ideas| tag_counts(&ideas).into_iter().map(|(tag, _)| tag).collect())
+            .unwrap_or_else(|err| {
+                debug!("Could not read existing tags: {}", err);
+                vec![]
+            });
+        let header = if known_tags.is_empty() {
+            String::from(">> Idea summary")
+        } else {
+            format!(
+                ">> Idea summary, add tags with #tag (e.g. {})",
+                known_tags
+                    .iter()
+                    .take(MAX_SUGGESTED_TAGS)
+                    .map(|tag| format!("#{}", tag))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        };
+
         let mut idea_summary = String::new();
+        let mut typed_tags = vec![];
 
         while idea_summary.is_empty() {
-            self.printer.input_header(">> Idea summary")?;
-            idea_summary = self.reader.read_input()?;
+            self.printer.input_header(&header)?;
+            (idea_summary, typed_tags) = split_tags(&self.reader.read_input()?);
         }
+        // Existing tags are only used instead of what was typed if the user says so
+        let mut tags: Vec<String> = vec![];
+        for tag in typed_tags {
+            let similar = similar_tags(&tag, &known_tags);
+            let tag = if similar.is_empty() {
+                tag
+            } else {
+                self.confirm_new_tag(&tag, &similar)?
+            };
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        let (repo_path, is_online) = self.open_repo(opts.offline)?;
+        let storage = self.storage(&repo_path)?;
+        let created = chrono::Local::now();
+        let idea_body = match self.template_name(opts.template.as_deref()) {
+            Some(name) => self.render_template(&name, &repo_path, &idea_summary, created)?,
+            None => String::new(),
+        };
+
+        let draft =
+            storage.prepare_idea(&idea_summary, &idea_body, &tags, self.author(), created)?;
+        let idea_file = storage.absolute(&draft.path);
+        let prepared = fs::read(&idea_file)?;
+
+        if let Err(err) = self
+            .program_opener
+            .open_editor_at(&idea_file.display().to_string(), draft.line)
+        {
+            storage.discard(&draft)?;
+            return Err(err);
+        }
+
+        // Quitting without saving leaves only what eureka wrote, in any layout
+        if fs::read(&idea_file)? == prepared {
+            debug!("Idea file was not changed in the editor");
+            storage.discard(&draft)?;
+            return self
+                .printer
+                .println("No changes were made to your idea file, nothing to commit");
+        }
+
+        let paths = storage.finish(&draft.path)?;
+        self.git_add_commit_push(idea_summary, &paths, NEW_IDEA, is_online)
+    }
+
+    fn capture_idea(&mut self, opts: AddOptions) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let (idea_summary, idea_body) = split_summary_body(opts.message, opts.body);
+        let (idea_summary, tags) = split_tags(&idea_summary);
+        if idea_summary.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Idea summary must not be empty",
+            ));
+        }
+
+        let (repo_path, is_online) = self.open_repo(opts.offline)?;
+        let storage = self.storage(&repo_path)?;
+        let created = chrono::Local::now();
+        let idea_body = match self.template_name(opts.template.as_deref()) {
+            Some(name) if idea_body.is_empty() => {
+                self.render_template(&name, &repo_path, &idea_summary, created)?
+            }
+            _ => idea_body,
+        };
+
+        let idea_path =
+            storage.write_idea(&idea_summary, &idea_body, &tags, self.author(), created)?;
+        debug!("Wrote idea to {}", idea_path.display());
+
+        let paths = storage.finish(&idea_path)?;
+        self.git_add_commit_push(idea_summary, &paths, NEW_IDEA, is_online)
+    }
+
+    /// Who new ideas are written by, according to git
+    fn author(&self) -> Option<String> {
+        self.git
+            .author()
+            .map_err(|err| debug!("Could not read the git author: {}", err))
+            .ok()
+    }
+
+    /// The template given on the command line, or else the profile's template
+    fn template_name(&self, template: Option<&str>) -> Option<String> {
+        template
+            .map(String::from)
+            .or_else(|| self.cm.config_read(Template).ok())
+    }
+
+    /// Read the template called `name` from the idea repo, or else from the
+    /// config directory, and fill it in for a new idea
+    fn render_template(
+        &self,
+        name: &str,
+        repo_path: &str,
+        summary: &str,
+        created: DateTime<Local>,
+    ) -> io::Result<String> {
+        let repo_template = template_path(&Path::new(repo_path).join(REPO_TEMPLATES_DIR), name)?;
+        let template = match fs::read_to_string(&repo_template) {
+            Ok(template) => template,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                self.cm.template_read(name).map_err(|err| match err.kind() {
+                    ErrorKind::NotFound => Error::new(
+                        ErrorKind::NotFound,
+                        format!(
+                            "No template called {} in {} of your idea repo or in your config directory",
+                            name, REPO_TEMPLATES_DIR
+                        ),
+                    ),
+                    _ => err,
+                })?
+            }
+            Err(err) => return Err(err),
+        };
+
+        let author = self.git.author().unwrap_or_else(|err| {
+            debug!("Could not read the git author: {}", err);
+            String::new()
+        });
+        Ok(render(
+            &template,
+            &TemplateVars {
+                summary: summary.to_string(),
+                date: created.format("%Y-%m-%d").to_string(),
+                author,
+                branch: self.branch(),
+            },
+        ))
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        self.ensure_config()?;
 
         let repo_path = self.cm.config_read(Repo)?;
-        // We can set initialize git now as we have the repo path
         self.git
             .init(&repo_path)
             .map_err(|git_err| Error::new(ErrorKind::InvalidInput, git_err))?;
 
-        self.program_opener
-            .open_editor(&format!("{}/README.md", &repo_path))
-            .and(self.git_add_commit_push(idea_summary))
+        if !self.pull(&repo_path)? {
+            return Err(Error::other(
+                "Could not reach the remote, your ideas are still committed locally",
+            ));
+        }
+
+        self.printer.println("Pushing your ideas..")?;
+        self.git
+            .push(&self.remote(), &self.branch())
+            .map_err(io::Error::other)?;
+        self.cm.pending_push_clear()?;
+        self.printer.println("Pushed!")
+    }
+
+    fn list(&mut self, opts: ListOptions) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let mut ideas = self.ideas()?;
+        ideas.retain(|idea| opts.matches(idea));
+        match opts.sort {
+            SortBy::Date => ideas.sort_by_key(|idea| Reverse(idea.date())),
+            SortBy::Title => ideas.sort_by_key(|idea| idea.title.to_lowercase()),
+        }
+        if opts.reverse {
+            ideas.reverse();
+        }
+        if let Some(limit) = opts.limit {
+            ideas.truncate(limit);
+        }
+
+        if ideas.is_empty() {
+            return self.printer.println("No ideas found");
+        }
+
+        let groups = if opts.group {
+            group_by_status(ideas)
+        } else {
+            vec![(String::new(), ideas)]
+        };
+        for (status, ideas) in groups {
+            if opts.group {
+                self.printer
+                    .group_header(&format!("{} ({})", status, ideas.len()))?;
+            }
+            for idea in &ideas {
+                self.printer.list_item(
+                    &list_date(idea.date()),
+                    &idea.id,
+                    &idea.title,
+                    &idea.tags,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn tags(&mut self) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let repo_path = self.cm.config_read(Repo)?;
+        let tags = tag_counts(&self.storage(&repo_path)?.ideas()?);
+        if tags.is_empty() {
+            return self.printer.println("No tags found");
+        }
+
+        let count_width = tags
+            .iter()
+            .map(|(_, count)| count.to_string().len())
+            .max()
+            .unwrap_or_default();
+        for (tag, count) in tags {
+            self.printer
+                .println(&format!("{:>width$}  {}", count, tag, width = count_width))?;
+        }
+        Ok(())
+    }
+
+    fn search(&mut self, opts: SearchOptions) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let pattern = if opts.regex {
+            opts.query.clone()
+        } else {
+            regex::escape(&opts.query)
+        };
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(opts.ignore_case)
+            .build()
+            .map_err(|err| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Invalid search pattern: {}", err),
+                )
+            })?;
+
+        let mut match_count = 0;
+        for idea in self.ideas()? {
+            let lines = iter::once(idea.title.as_str()).chain(idea.body.lines());
+            let matches = matching_lines(&regex, lines);
+            if matches.is_empty() {
+                continue;
+            }
+
+            match_count += 1;
+            self.printer
+                .list_item(&list_date(idea.date()), &idea.id, &idea.title, &idea.tags)?;
+            for (line, ranges) in matches {
+                self.printer.search_match(line, &ranges)?;
+            }
+        }
+
+        if opts.commits {
+            for commit in self.git.log().map_err(io::Error::other)? {
+                let matches = matching_lines(&regex, commit.message.lines());
+                if matches.is_empty() {
+                    continue;
+                }
+
+                match_count += 1;
+                let id = commit.id.to_string();
+                self.printer.list_item(
+                    &list_date(Some(commit.time)),
+                    &id[..SHORT_COMMIT_ID_LEN],
+                    &commit.summary,
+                    &[],
+                )?;
+                for (line, ranges) in matches {
+                    self.printer.search_match(line, &ranges)?;
+                }
+            }
+        }
+
+        if match_count == 0 {
+            return self.printer.println("No matches found");
+        }
+        Ok(())
+    }
+
+    fn edit(&mut self, target: &str) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let (repo_path, is_online) = self.open_repo(false)?;
+        let storage = self.storage(&repo_path)?;
+        let idea = find_idea(storage.ideas()?, target)?;
+
+        // Only the idea itself is opened, not the whole file it lives in
+        let text = idea.to_string();
+        let edit_file = tempfile::Builder::new()
+            .prefix("eureka-")
+            .suffix(".md")
+            .tempfile()?;
+        fs::write(edit_file.path(), &text)?;
+        let edited = match self
+            .program_opener
+            .open_editor(&edit_file.path().display().to_string())
+            .and_then(|_| fs::read_to_string(edit_file.path()))
+        {
+            Ok(edited) => edited,
+            Err(err) => return Err(keep_temp_file(edit_file, err)),
+        };
+
+        if edited == text {
+            debug!("Idea was not changed in the editor");
+            return self
+                .printer
+                .println("No changes were made to your idea, nothing to commit");
+        }
+
+        let title = Idea::parse(idea.format(), &idea.path, &edited)
+            .map(|edited_idea| edited_idea.title)
+            .unwrap_or(idea.title.clone());
+        let paths = match storage.replace(&idea, &edited) {
+            Ok(paths) => paths,
+            Err(err) => return Err(keep_temp_file(edit_file, err)),
+        };
+        edit_file.close()?;
+        self.git_add_commit_push(
+            format!("Edit: {}", title),
+            &paths,
+            "your changes",
+            is_online,
+        )
+    }
+
+    /// Delete an idea, or move it to the archive, and commit that
+    fn remove(&mut self, target: &str, archive: bool) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let (repo_path, is_online) = self.open_repo(false)?;
+        let storage = self.storage(&repo_path)?;
+        let idea = find_idea(storage.ideas()?, target)?;
+
+        let (paths, commit_subject) = if archive {
+            (storage.archive(&idea)?, format!("Archive: {}", idea.title))
+        } else {
+            (storage.remove(&idea)?, format!("Remove: {}", idea.title))
+        };
+        self.git_add_commit_push(commit_subject, &paths, "your changes", is_online)
+    }
+
+    fn undo(&mut self) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let (repo_path, is_online) = self.open_repo(false)?;
+        let titles = self
+            .storage(&repo_path)?
+            .ideas()?
+            .into_iter()
+            .map(|idea| idea.title)
+            .collect::<HashSet<String>>();
+        let commits = self.git.log().map_err(io::Error::other)?;
+        let commit = last_undoable(&commits, &titles).ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                "Found no commit made by eureka to undo",
+            )
+        })?;
+
+        let id = commit.id.to_string();
+        self.printer.println(&format!(
+            "Undoing {} {}..",
+            &id[..SHORT_COMMIT_ID_LEN],
+            commit.summary
+        ))?;
+        let message = format!(
+            "Undo: {}\n\n{}{}.",
+            commit.summary, REVERTS_COMMIT, commit.id
+        );
+        self.git
+            .revert(commit.id, &message)
+            .map_err(io::Error::other)?;
+        self.printer.println("Undone!")?;
+
+        self.push_committed("the undo", is_online)
+    }
+
+    fn doctor(&mut self) -> io::Result<()> {
+        let mut problems = 0;
+
+        let repo_path = match self.cm.config_read(Repo) {
+            Ok(repo_path) => {
+                self.printer
+                    .println(&format!("[ok] Idea repo is configured: {}", repo_path))?;
+                repo_path
+            }
+            Err(err) => {
+                self.printer
+                    .error(&format!("[error] Could not read config: {}", err))?;
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    "No config found, run eureka to complete first time setup",
+                ));
+            }
+        };
+
+        match self.git.init(&repo_path) {
+            Ok(_) => {
+                self.printer.println("[ok] Idea repo is a git repository")?;
+
+                let remote = self.remote();
+                if self.git.remotes().unwrap_or_default().contains(&remote) {
+                    self.printer
+                        .println(&format!("[ok] Remote exists: {}", remote))?;
+                } else {
+                    problems += 1;
+                    self.printer
+                        .error(&format!("[error] Remote is missing: {}", remote))?;
+                }
+            }
+            Err(err) => {
+                problems += 1;
+                self.printer
+                    .error(&format!("[error] Could not open idea repo: {}", err))?;
+            }
+        }
+
+        let idea_file = format!("{}/{}", &repo_path, self.idea_file().display());
+        if Path::new(&idea_file).is_file() {
+            self.printer
+                .println(&format!("[ok] Idea file exists: {}", idea_file))?;
+        } else {
+            problems += 1;
+            self.printer
+                .error(&format!("[error] Idea file is missing: {}", idea_file))?;
+        }
+
+        if self.cm.pending_push_exists() {
+            problems += 1;
+            self.printer
+                .error("[error] Some ideas haven't been pushed yet, run `eureka sync`")?;
+        }
+
+        if problems > 0 {
+            return Err(Error::other(format!("Found {} problem(s)", problems)));
+        }
+
+        Ok(())
     }
 
     fn clear_config(&self) -> io::Result<()> {
         self.cm.config_rm()
     }
 
-    fn open_idea_file(&self) -> io::Result<()> {
+    fn show_config(&mut self, origin: bool) -> io::Result<()> {
+        let width = ConfigType::ALL
+            .iter()
+            .map(|config_type| config_type.key().len())
+            .max()
+            .unwrap_or_default();
+        for config_type in ConfigType::ALL {
+            let (value, source) = match self.cm.config_read(config_type) {
+                Ok(value) => (value, self.cm.config_origin(config_type)?.to_string()),
+                // E.g. a profile that doesn't exist
+                Err(err) if err.kind() == ErrorKind::InvalidInput => return Err(err),
+                Err(_) => match default_value(config_type) {
+                    Some(value) => (value, String::from("default")),
+                    None => (String::from("not set"), String::new()),
+                },
+            };
+
+            let line = format!("{:<width$}  {}", config_type.key(), value, width = width);
+            if origin && !source.is_empty() {
+                self.printer.println(&format!("{}  ({})", line, source))?;
+            } else {
+                self.printer.println(&line)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn get_config(&mut self, config_type: ConfigType) -> io::Result<()> {
+        let value = match self.cm.config_read(config_type) {
+            Ok(value) => value,
+            Err(err) if err.kind() == ErrorKind::InvalidInput => return Err(err),
+            Err(_) => default_value(config_type).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("{} is not set", config_type.key()),
+                )
+            })?,
+        };
+        self.printer.println(&value)
+    }
+
+    fn set_config(&mut self, config_type: ConfigType, value: String) -> io::Result<()> {
+        config_type.validate(&value)?;
+        self.cm.config_write(config_type, value)?;
+        self.warn_overridden(config_type)
+    }
+
+    fn unset_config(&mut self, config_type: ConfigType) -> io::Result<()> {
+        if config_type == Repo {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "The repo can't be unset, use `eureka config clear` to start over",
+            ));
+        }
+        self.cm.config_unset(config_type)?;
+        self.warn_overridden(config_type)
+    }
+
+    /// A stored setting has no effect when the command line or a `.eureka.toml` sets it too
+    fn warn_overridden(&mut self, config_type: ConfigType) -> io::Result<()> {
+        match self.cm.config_origin(config_type) {
+            Ok(origin @ (ConfigOrigin::CommandLine | ConfigOrigin::Local(_))) => {
+                self.printer.println(&format!(
+                    "Note that {} is set by {}, which wins over the config",
+                    config_type.key(),
+                    origin
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn list_config(&mut self) -> io::Result<()> {
+        let values = self.cm.config_list()?;
+        let width = values
+            .iter()
+            .map(|(config_type, _)| config_type.key().len())
+            .max()
+            .unwrap_or_default();
+        for (config_type, value) in values {
+            self.printer.println(&format!(
+                "{:<width$}  {}",
+                config_type.key(),
+                value,
+                width = width
+            ))?;
+        }
+        Ok(())
+    }
+
+    fn edit_config(&mut self) -> io::Result<()> {
+        let text = self.cm.config_text_read()?;
+        let edit_file = tempfile::Builder::new()
+            .prefix("eureka-")
+            .suffix(".json")
+            .tempfile()?;
+        fs::write(edit_file.path(), &text)?;
+
+        let result = loop {
+            let edited = self
+                .program_opener
+                .open_editor(&edit_file.path().display().to_string())
+                .and_then(|_| fs::read_to_string(edit_file.path()));
+            let edited = match edited {
+                Ok(edited) if edited == text => {
+                    debug!("Config was not changed in the editor");
+                    break Ok(());
+                }
+                Ok(edited) => edited,
+                Err(err) => break Err(err),
+            };
+
+            // Give the chance to fix a mistake instead of losing the changes
+            match self.cm.config_text_write(&edited) {
+                Err(err)
+                    if matches!(err.kind(), ErrorKind::InvalidData | ErrorKind::InvalidInput) =>
+                {
+                    self.printer.error(&err.to_string())?;
+                    let answer = self.ask_with_default("Edit the config again? y/n", "y")?;
+                    if !answer.eq_ignore_ascii_case("y") {
+                        break Err(Error::new(err.kind(), "The config was left as it was"));
+                    }
+                }
+                result => break result,
+            }
+        };
+        match result {
+            Ok(()) => edit_file.close(),
+            Err(err) => Err(keep_temp_file(edit_file, err)),
+        }
+    }
+
+    fn profiles(&mut self, command: ProfileCommand) -> io::Result<()> {
+        let mut profiles = self.cm.profiles_read()?;
+        match command {
+            ProfileCommand::List => {
+                let width = profiles.profiles.keys().map(String::len).max();
+                for (name, profile) in &profiles.profiles {
+                    let marker = if *name == profiles.default { "*" } else { " " };
+                    self.printer.println(&format!(
+                        "{} {:<width$}  {}",
+                        marker,
+                        name,
+                        profile
+                            .repo
+                            .as_ref()
+                            .map(|repo| repo.display().to_string())
+                            .unwrap_or_default(),
+                        width = width.unwrap_or_default()
+                    ))?;
+                }
+                Ok(())
+            }
+            ProfileCommand::Add(name, profile) => {
+                if !is_profile_name(&name) {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "Profile names only contain letters, digits, - and _: {}",
+                            name
+                        ),
+                    ));
+                }
+                if profiles.profiles.contains_key(&name) {
+                    return Err(Error::new(
+                        ErrorKind::AlreadyExists,
+                        format!("There already is a profile called {}", name),
+                    ));
+                }
+                if !profile.repo.as_deref().is_some_and(Path::is_absolute) {
+                    return Err(Error::new(ErrorKind::InvalidInput, "Path must be absolute"));
+                }
+                if let Some(storage) = &profile.storage {
+                    storage.parse::<StorageLayout>()?;
+                }
+
+                profiles.profiles.insert(name.clone(), profile);
+                self.cm.profiles_write(profiles)?;
+                self.printer.println(&format!(
+                    "Added profile {0}, use it with --profile {0}",
+                    name
+                ))
+            }
+            ProfileCommand::Remove(name) => {
+                known_profile(&profiles, &name)?;
+                if name == profiles.default {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!(
+                            "{} is the default profile, make another profile the default first",
+                            name
+                        ),
+                    ));
+                }
+
+                profiles.profiles.remove(&name);
+                self.cm.profiles_write(profiles)?;
+                self.printer.println(&format!("Removed profile {}", name))
+            }
+            ProfileCommand::Default(name) => {
+                known_profile(&profiles, &name)?;
+
+                profiles.default = name.clone();
+                self.cm.profiles_write(profiles)?;
+                self.printer
+                    .println(&format!("{} is now the default profile", name))
+            }
+        }
+    }
+
+    fn view(&mut self, opts: ViewOptions) -> io::Result<()> {
+        let is_filtered = opts.status.is_some() || opts.group;
+        if opts.raw && !is_filtered {
+            return self.open_idea_file();
+        }
+
+        let repo_path = self.cm.config_read(Repo)?;
+        let contents = if is_filtered {
+            selected_ideas(self.storage(&repo_path)?.ideas()?, &opts)
+        } else {
+            fs::read_to_string(Path::new(&repo_path).join(self.idea_file()))?
+        };
+        if opts.raw {
+            return self.page(contents.as_bytes());
+        }
+
+        match self.printer.terminal_width() {
+            Some(width) => {
+                let mut rendered = Ansi::new(vec![]);
+                markdown::render(&contents, width, &mut rendered)?;
+                self.page(&rendered.into_inner())
+            }
+            // Not a terminal, so there's no one to page for
+            None => {
+                let mut rendered = NoColor::new(vec![]);
+                markdown::render(&contents, markdown::DEFAULT_WIDTH, &mut rendered)?;
+                self.printer
+                    .print(&String::from_utf8_lossy(&rendered.into_inner()))
+            }
+        }
+    }
+
+    /// Show `contents` in the pager. Pagers need a file, so it's written to one.
+    fn page(&self, contents: &[u8]) -> io::Result<()> {
+        let view_file = tempfile::Builder::new()
+            .prefix("eureka-")
+            .suffix(".md")
+            .tempfile()?;
+        fs::write(view_file.path(), contents)?;
         self.program_opener
-            .open_pager(&format!("{}/README.md", self.cm.config_read(Repo)?))
+            .open_pager(&view_file.path().display().to_string())
+    }
+
+    fn set_status(&mut self, target: &str, status: IdeaStatus) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let (repo_path, is_online) = self.open_repo(false)?;
+        let storage = self.storage(&repo_path)?;
+        let mut idea = find_idea(storage.ideas()?, target)?;
+
+        if idea.status() == status.to_string() {
+            return self
+                .printer
+                .println(&format!("\"{}\" is already {}", idea.title, status));
+        }
+
+        idea.status = Some(status.to_string());
+        let paths = storage.replace(&idea, &idea.to_string())?;
+        self.git_add_commit_push(
+            format!("Status: {} is {}", idea.title, status),
+            &paths,
+            "the new status",
+            is_online,
+        )
+    }
+
+    fn set_tags(&mut self, target: &str, tags: &[String]) -> io::Result<()> {
+        self.ensure_config()?;
+
+        let tags = parse_tags(tags)?;
+        let (repo_path, is_online) = self.open_repo(false)?;
+        let storage = self.storage(&repo_path)?;
+        let mut idea = find_idea(storage.ideas()?, target)?;
+
+        if idea.tags == tags {
+            return self
+                .printer
+                .println(&format!("\"{}\" already has those tags", idea.title));
+        }
+
+        let message = if tags.is_empty() {
+            format!("Tags: {} has no tags", idea.title)
+        } else {
+            format!("Tags: {} is tagged #{}", idea.title, tags.join(" #"))
+        };
+        idea.tags = tags;
+        let paths = storage.replace(&idea, &idea.to_string())?;
+        self.git_add_commit_push(message, &paths, "the new tags", is_online)
+    }
+
+    fn browse(&mut self) -> io::Result<()> {
+        self.ensure_config()?;
+        browse::run(self)
+    }
+
+    fn open_idea_file(&self) -> io::Result<()> {
+        self.program_opener.open_pager(&format!(
+            "{}/{}",
+            self.cm.config_read(Repo)?,
+            self.idea_file().display()
+        ))
+    }
+
+    /// Open the idea repo and check out the configured branch. Unless
+    /// offline it's also brought up to date with the remote, and ideas
+    /// committed while offline are pushed. Returns the repo path and whether
+    /// the remote could be reached.
+    fn open_repo(&mut self, offline: bool) -> io::Result<(String, bool)> {
+        let repo_path = self.cm.config_read(Repo)?;
+        // We can set initialize git now as we have the repo path
+        self.git
+            .init(&repo_path)
+            .map_err(|git_err| Error::new(ErrorKind::InvalidInput, git_err))?;
+
+        if offline {
+            self.git
+                .checkout_branch(&self.branch())
+                .map_err(io::Error::other)?;
+            return Ok((repo_path, false));
+        }
+
+        let is_online = self.pull(&repo_path)?;
+        if is_online && self.cm.pending_push_exists() {
+            self.push_pending()?;
+        }
+
+        Ok((repo_path, is_online))
+    }
+
+    /// Bring the local branch up to date with the remote so the push
+    /// after committing isn't rejected. Returns `false` if the remote
+    /// couldn't be reached.
+    fn pull(&mut self, repo_path: &str) -> io::Result<bool> {
+        let branch_name = self.branch();
+        let remote_name = self.remote();
+        self.git
+            .checkout_branch(&branch_name)
+            .map_err(io::Error::other)?;
+
+        self.printer.println(&format!(
+            "Pulling latest ideas from {}/{}..",
+            &remote_name, &branch_name
+        ))?;
+        match self.git.pull(&remote_name, &branch_name) {
+            Ok(_) => Ok(true),
+            Err(err) if err.code() == git2::ErrorCode::Conflict => Err(Error::other(format!(
+                "Could not update {} from {}/{}: {}. Resolve this manually (e.g. with `git pull --rebase`) and run eureka again",
+                repo_path,
+                &remote_name,
+                &branch_name,
+                err.message()
+            ))),
+            Err(err) if is_unreachable(&err) => {
+                self.printer.error(&format!(
+                    "Could not pull from {}/{}: {}",
+                    &remote_name,
+                    &branch_name,
+                    err.message()
+                ))?;
+                self.printer
+                    .println("Continuing offline, ideas are committed locally for now")?;
+                Ok(false)
+            }
+            Err(err) => Err(Error::other(format!(
+                "Could not pull from {}/{}: {}",
+                &remote_name,
+                &branch_name,
+                err.message()
+            ))),
+        }
+    }
+
+    fn push_pending(&mut self) -> io::Result<()> {
+        self.printer
+            .println("Pushing ideas committed while offline..")?;
+        match self.git.push(&self.remote(), &self.branch()) {
+            Ok(_) => {
+                self.cm.pending_push_clear()?;
+                self.printer.println("Pushed!")
+            }
+            Err(err) => self
+                .printer
+                .error(&format!("Could not push: {}", err.message())),
+        }
     }
 
-    fn git_add_commit_push(&mut self, commit_subject: String) -> io::Result<()> {
-        let branch_name = "main";
+    /// Commit `paths` and push them unless offline. `change` describes what
+    /// is being committed, e.g. "your new idea".
+    fn git_add_commit_push(
+        &mut self,
+        commit_subject: String,
+        paths: &[PathBuf],
+        change: &str,
+        is_online: bool,
+    ) -> io::Result<()> {
+        let branch_name = self.branch();
         self.printer.println(&format!(
-            "Adding and committing your new idea to {}..",
-            &branch_name
+            "Adding and committing {} to {}..",
+            change, &branch_name
         ))?;
         self.git
-            .checkout_branch(branch_name)
-            .and_then(|_| self.git.add())
+            .checkout_branch(&branch_name)
+            .and_then(|_| self.git.add(paths))
             .and_then(|_| self.git.commit(commit_subject.as_str()))
-            .map_err(|err| io::Error::new(ErrorKind::Other, err))?;
+            .map_err(io::Error::other)?;
         self.printer.println("Added and committed!")?;
 
-        self.printer.println("Pushing your new idea..")?;
-        self.git
-            .push(branch_name)
-            .map_err(|err| io::Error::new(ErrorKind::Other, err))?;
-        self.printer.println("Pushed!")?;
+        self.push_committed(change, is_online)
+    }
 
-        Ok(())
+    /// Push what was just committed, or leave it for `sync` when offline
+    fn push_committed(&mut self, change: &str, is_online: bool) -> io::Result<()> {
+        let branch_name = self.branch();
+        let remote_name = self.remote();
+
+        if !is_online {
+            self.cm.pending_push_mark()?;
+            return self.printer.println(&format!(
+                "Run `eureka sync` to push {} once you're back online",
+                change
+            ));
+        }
+
+        self.printer.println(&format!("Pushing {}..", change))?;
+        match self.git.push(&remote_name, &branch_name) {
+            Ok(_) => {
+                if self.cm.pending_push_exists() {
+                    self.cm.pending_push_clear()?;
+                }
+                self.printer.println("Pushed!")
+            }
+            Err(err) => {
+                self.cm.pending_push_mark()?;
+                self.printer
+                    .error(&format!("Could not push: {}", err.message()))?;
+                self.printer.println(&format!(
+                    "Committed {} locally, run `eureka sync` to push it later",
+                    change
+                ))
+            }
+        }
     }
 
-    fn setup_repo_path(&mut self) -> io::Result<()> {
+    fn setup_repo_path(&mut self) -> io::Result<String> {
         loop {
             self.printer
                 .input_header("Absolute path to your idea repo")?;
@@ -163,14 +1390,396 @@ where
             let path = Path::new(user_input);
 
             if path.is_absolute() {
-                break self.cm.config_write(Repo, path.display().to_string());
+                let repo_path = path.display().to_string();
+                self.cm.config_write(Repo, repo_path.clone())?;
+                break Ok(repo_path);
             } else {
                 self.printer.error("Path must be absolute")?;
             }
         }
     }
 
+    /// Detect which branch and remote the repo uses, falling back to
+    /// `main` and `origin` when the repo can't be opened.
+    fn detect_branch_and_remote(&mut self, repo_path: &str) -> (String, String) {
+        let (branch, remote) = match self.git.init(repo_path) {
+            Ok(_) => {
+                let branch = self.git.current_branch().ok().flatten();
+                let remotes = self.git.remotes().unwrap_or_default();
+                let remote = if remotes.iter().any(|remote| remote == DEFAULT_REMOTE) {
+                    Some(DEFAULT_REMOTE.to_string())
+                } else {
+                    remotes.into_iter().next()
+                };
+                (branch, remote)
+            }
+            Err(err) => {
+                debug!("Could not open repo to detect defaults: {}", err);
+                (None, None)
+            }
+        };
+
+        (
+            branch.unwrap_or_else(|| DEFAULT_BRANCH.to_string()),
+            remote.unwrap_or_else(|| DEFAULT_REMOTE.to_string()),
+        )
+    }
+
+    fn setup_storage_layout(&mut self) -> io::Result<StorageLayout> {
+        loop {
+            let user_input = self.ask_with_default(
+                "Storage layout, single-file or file-per-idea",
+                &StorageLayout::default().to_string(),
+            )?;
+
+            match user_input.parse::<StorageLayout>() {
+                Ok(layout) => break Ok(layout),
+                Err(err) => self.printer.error(&err.to_string())?,
+            }
+        }
+    }
+
+    fn setup_program(&mut self, question: &str) -> io::Result<Option<String>> {
+        loop {
+            self.printer.input_header(question)?;
+            let user_input = self.reader.read_input()?;
+            if user_input.is_empty() {
+                break Ok(None);
+            }
+
+            match find_program(&user_input) {
+                Ok(_) => break Ok(Some(user_input)),
+                Err(err) => self.printer.error(&err.to_string())?,
+            }
+        }
+    }
+
+    fn ask_with_default(&mut self, question: &str, default: &str) -> io::Result<String> {
+        self.printer
+            .input_header(&format!("{} (default: {})", question, default))?;
+        let user_input = self.reader.read_input()?;
+
+        if user_input.is_empty() {
+            Ok(default.to_string())
+        } else {
+            Ok(user_input)
+        }
+    }
+
+    fn branch(&self) -> String {
+        self.cm
+            .config_read(Branch)
+            .unwrap_or_else(|_| DEFAULT_BRANCH.to_string())
+    }
+
+    fn remote(&self) -> String {
+        self.cm
+            .config_read(Remote)
+            .unwrap_or_else(|_| DEFAULT_REMOTE.to_string())
+    }
+
+    fn storage(&self, repo_path: &str) -> io::Result<Storage> {
+        let layout = match self.cm.config_read(StorageConfig) {
+            Ok(layout) => layout.parse()?,
+            Err(_) => StorageLayout::default(),
+        };
+        let position = match self.cm.config_read(Position) {
+            Ok(position) => position.parse()?,
+            Err(_) => IdeaPosition::default(),
+        };
+        Ok(Storage::new(repo_path, layout)
+            .with_position(position)
+            .with_file(self.idea_file()))
+    }
+
+    /// The file that holds the ideas, or their index, relative to the repo
+    fn idea_file(&self) -> PathBuf {
+        self.cm
+            .config_read(IdeaFileConfig)
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(IDEA_FILE_NAME))
+    }
+
+    fn ensure_config(&self) -> io::Result<()> {
+        match self.cm.config_read(Repo) {
+            Ok(_) => Ok(()),
+            // E.g. a profile that doesn't exist
+            Err(err) if err.kind() == ErrorKind::InvalidInput => Err(err),
+            Err(_) => Err(Error::new(
+                ErrorKind::NotFound,
+                "No config found, run eureka without arguments to complete first time setup",
+            )),
+        }
+    }
+
     fn is_config_missing(&self) -> bool {
         self.cm.config_read(Repo).is_err()
     }
 }
+
+/// Resolve the idea summary and body from what was given on the command line.
+/// Without an explicit summary the first non-empty line of the body is used.
+fn split_summary_body(message: Option<String>, body: Option<String>) -> (String, String) {
+    let body = body.unwrap_or_default();
+    match message {
+        Some(message) => (message.trim().to_string(), body.trim().to_string()),
+        None => {
+            let mut lines = body.trim_start().splitn(2, '\n');
+            let summary = lines.next().unwrap_or_default().trim().to_string();
+            let rest = lines.next().unwrap_or_default().trim().to_string();
+            (summary, rest)
+        }
+    }
+}
+
+/// Take `#tag`s out of an idea summary. Tags start with a letter, so
+/// references like `#123` are left alone.
+/// The tags in use that start with `tag`, if `tag` itself isn't in use yet
+fn similar_tags<'a>(tag: &str, known_tags: &'a [String]) -> Vec<&'a String> {
+    if known_tags.iter().any(|known| known == tag) {
+        return vec![];
+    }
+    known_tags
+        .iter()
+        .filter(|known| known.starts_with(tag))
+        .take(MAX_SUGGESTED_TAGS)
+        .collect()
+}
+
+fn split_tags(summary: &str) -> (String, Vec<String>) {
+    let mut words = vec![];
+    let mut tags: Vec<String> = vec![];
+    for word in summary.split_whitespace() {
+        let tag = word.strip_prefix('#').filter(|tag| {
+            tag.starts_with(char::is_alphabetic)
+                && tag
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '/'))
+        });
+        match tag {
+            Some(tag) if !tags.iter().any(|known| known == tag) => tags.push(tag.to_string()),
+            Some(_) => {}
+            None => words.push(word),
+        }
+    }
+    (words.join(" "), tags)
+}
+
+/// What's used for a setting that isn't configured
+fn default_value(config_type: ConfigType) -> Option<String> {
+    match config_type {
+        ConfigType::File => Some(IDEA_FILE_NAME.to_string()),
+        ConfigType::Branch => Some(DEFAULT_BRANCH.to_string()),
+        ConfigType::Remote => Some(DEFAULT_REMOTE.to_string()),
+        ConfigType::Storage => Some(StorageLayout::default().to_string()),
+        ConfigType::Position => Some(IdeaPosition::default().to_string()),
+        ConfigType::Repo | ConfigType::Template | ConfigType::Editor | ConfigType::Pager => None,
+    }
+}
+
+fn known_profile(profiles: &Profiles, name: &str) -> io::Result<()> {
+    if profiles.profiles.contains_key(name) {
+        return Ok(());
+    }
+    Err(Error::new(
+        ErrorKind::NotFound,
+        format!("No profile called {}", name),
+    ))
+}
+
+/// Profile names end up in file names, so keep them simple
+fn is_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_'))
+}
+
+/// Tags given one per word, with or without the leading #
+fn parse_tags(words: &[String]) -> io::Result<Vec<String>> {
+    let text = words
+        .iter()
+        .map(|word| format!("#{}", word.trim_start_matches('#')))
+        .collect::<Vec<String>>()
+        .join(" ");
+    let (rest, tags) = split_tags(&text);
+    if !rest.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "Tags start with a letter and only contain letters, digits, -, _ and /: {}",
+                rest
+            ),
+        ));
+    }
+    Ok(tags)
+}
+
+/// Every tag with the number of ideas that have it, most used first
+fn tag_counts(ideas: &[Idea]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = vec![];
+    for tag in ideas.iter().flat_map(|idea| &idea.tags) {
+        match counts.iter_mut().find(|(known, _)| known == tag) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((tag.clone(), 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Split ideas by status, keeping their order. Statuses follow the
+/// workflow, statuses eureka doesn't know come last.
+fn group_by_status(ideas: Vec<Idea>) -> Vec<(String, Vec<Idea>)> {
+    let mut groups = IdeaStatus::ALL
+        .iter()
+        .map(|status| (status.to_string(), vec![]))
+        .collect::<Vec<(String, Vec<Idea>)>>();
+    for idea in ideas {
+        match groups
+            .iter_mut()
+            .find(|(status, _)| status == idea.status())
+        {
+            Some((_, group)) => group.push(idea),
+            None => groups.push((idea.status().to_string(), vec![idea])),
+        }
+    }
+    groups.retain(|(_, group)| !group.is_empty());
+    groups
+}
+
+/// The newest commit made by eureka that isn't an undo and hasn't been
+/// undone yet. Commits made by eureka are told apart from the ones made by
+/// hand by their subject, which either starts with one of eureka's prefixes
+/// or is the title of a new idea that is still in `titles`.
+fn last_undoable<'a>(
+    commits: &'a [CommitInfo],
+    titles: &HashSet<String>,
+) -> Option<&'a CommitInfo> {
+    let by_eureka = |commit: &&CommitInfo| {
+        COMMIT_PREFIXES
+            .iter()
+            .any(|prefix| commit.summary.starts_with(prefix))
+            || titles.contains(&commit.summary)
+    };
+
+    let mut undone = HashSet::new();
+    for commit in commits.iter().filter(by_eureka) {
+        let reverted = commit
+            .message
+            .lines()
+            .find_map(|line| line.strip_prefix(REVERTS_COMMIT));
+        match reverted {
+            Some(id) => {
+                undone.insert(id.trim_end_matches('.').to_string());
+            }
+            None if !undone.contains(&commit.id.to_string()) => return Some(commit),
+            None => {}
+        }
+    }
+    None
+}
+
+/// Keep a temporary file the user edited instead of deleting it, and point
+/// to it from `err` so the edits aren't lost
+fn keep_temp_file(file: NamedTempFile, err: Error) -> Error {
+    match file.keep() {
+        Ok((_, path)) => Error::new(
+            err.kind(),
+            format!("{}. Your edits are kept in {}", err, path.display()),
+        ),
+        Err(_) => err,
+    }
+}
+
+/// Find the idea with the given id, or else the only idea whose title
+/// contains `target`, ignoring case
+fn find_idea(mut ideas: Vec<Idea>, target: &str) -> io::Result<Idea> {
+    if let Some(index) = ideas.iter().position(|idea| idea.id == target) {
+        return Ok(ideas.swap_remove(index));
+    }
+
+    let query = target.to_lowercase();
+    let mut matches = ideas
+        .into_iter()
+        .filter(|idea| idea.title.to_lowercase().contains(&query))
+        .collect::<Vec<Idea>>();
+
+    // A title that matches exactly wins over titles that only contain the query
+    if let Some(index) = matches
+        .iter()
+        .position(|idea| idea.title.to_lowercase() == query)
+    {
+        return Ok(matches.swap_remove(index));
+    }
+
+    match matches.len() {
+        0 => Err(Error::new(
+            ErrorKind::NotFound,
+            format!("No idea matches \"{}\"", target),
+        )),
+        1 => Ok(matches.remove(0)),
+        _ => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "\"{}\" matches several ideas, use one of their ids instead: {}",
+                target,
+                matches
+                    .iter()
+                    .map(|idea| format!("{} ({})", idea.id, idea.title))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        )),
+    }
+}
+
+/// The ideas to view as Markdown, grouped by status if asked for
+fn selected_ideas(mut ideas: Vec<Idea>, opts: &ViewOptions) -> String {
+    ideas.retain(|idea| {
+        opts.status
+            .is_none_or(|status| idea.status() == status.to_string())
+    });
+    let groups = if opts.group {
+        group_by_status(ideas)
+    } else {
+        vec![(String::new(), ideas)]
+    };
+
+    let mut contents = String::new();
+    for (status, ideas) in groups {
+        if opts.group {
+            contents.push_str(&format!("# {}\n\n", status));
+        }
+        for idea in ideas {
+            contents.push_str(&format!("## {}\n\n", idea.title));
+            if !idea.body.trim().is_empty() {
+                contents.push_str(&format!("{}\n\n", idea.body.trim()));
+            }
+        }
+    }
+    contents
+}
+
+fn list_date(date: Option<DateTime<FixedOffset>>) -> String {
+    date.map(|date| date.format("%Y-%m-%d").to_string())
+        // Keep the columns aligned for ideas without a date
+        .unwrap_or_else(|| " ".repeat(10))
+}
+
+/// Every line with at least one match, along with where the matches are
+fn matching_lines<'a>(
+    regex: &Regex,
+    lines: impl Iterator<Item = &'a str>,
+) -> Vec<(&'a str, Vec<Range<usize>>)> {
+    lines
+        .filter_map(|line| {
+            let ranges = regex
+                .find_iter(line)
+                .map(|found| found.range())
+                .filter(|range| !range.is_empty())
+                .collect::<Vec<_>>();
+            (!ranges.is_empty()).then_some((line, ranges))
+        })
+        .collect()
+}
@@ -0,0 +1,454 @@
+use std::io;
+
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use termcolor::{Color, ColorSpec, WriteColor};
+
+// Used when the output isn't a terminal
+pub const DEFAULT_WIDTH: usize = 80;
+// Wrapping narrower than this makes deeply nested text unreadable
+const MIN_TEXT_WIDTH: usize = 20;
+const BULLET: &str = "• ";
+const QUOTE_BAR: &str = "│ ";
+const CODE_INDENT: &str = "    ";
+
+/// Render Markdown for the terminal, word wrapped to `width` columns.
+/// Colors are only written if `out` supports them.
+pub fn render<W: WriteColor>(markdown: &str, width: usize, out: &mut W) -> io::Result<()> {
+    let options = Options::ENABLE_TASKLISTS
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+    let mut renderer = Renderer::new(out, width);
+    for event in Parser::new_ext(markdown, options) {
+        renderer.event(event)?;
+    }
+    renderer.flush()
+}
+
+#[derive(Clone, Default)]
+struct Style {
+    heading: Option<HeadingLevel>,
+    is_bold: bool,
+    is_italic: bool,
+    is_strikethrough: bool,
+    is_code: bool,
+    is_link: bool,
+}
+
+impl Style {
+    fn color_spec(&self) -> ColorSpec {
+        let mut spec = ColorSpec::new();
+        match self.heading {
+            Some(HeadingLevel::H1) => {
+                spec.set_fg(Some(Color::Magenta)).set_underline(true);
+            }
+            Some(HeadingLevel::H2) => {
+                spec.set_fg(Some(Color::Cyan));
+            }
+            Some(_) => {
+                spec.set_fg(Some(Color::Blue));
+            }
+            None => {}
+        }
+        if self.is_code {
+            spec.set_fg(Some(Color::Yellow));
+        }
+        if self.is_link {
+            spec.set_fg(Some(Color::Blue)).set_underline(true);
+        }
+        spec.set_bold(self.is_bold || self.heading.is_some())
+            .set_italic(self.is_italic)
+            .set_strikethrough(self.is_strikethrough);
+        spec
+    }
+}
+
+enum Piece {
+    Text(String, ColorSpec),
+    LineBreak,
+}
+
+struct Renderer<'a, W> {
+    out: &'a mut W,
+    width: usize,
+    style: Style,
+    // Text of the current block, written once the block ends
+    pieces: Vec<Piece>,
+    // The next number of every list the current block is in, None if unordered
+    lists: Vec<Option<u64>>,
+    // Bullet of the list item whose first line hasn't been written yet
+    bullet: Option<String>,
+    quote_depth: usize,
+    code_block: Option<String>,
+    // Where the link text starts in `pieces` and where the link goes
+    link: Option<(usize, String)>,
+    is_in_metadata: bool,
+    is_after_blank_line: bool,
+}
+
+impl<'a, W: WriteColor> Renderer<'a, W> {
+    fn new(out: &'a mut W, width: usize) -> Self {
+        Renderer {
+            out,
+            width,
+            style: Style::default(),
+            pieces: vec![],
+            lists: vec![],
+            bullet: None,
+            quote_depth: 0,
+            code_block: None,
+            link: None,
+            is_in_metadata: false,
+            is_after_blank_line: true,
+        }
+    }
+
+    fn event(&mut self, event: Event) -> io::Result<()> {
+        match event {
+            Event::Start(tag) => self.start(tag)?,
+            Event::End(tag) => self.end(tag)?,
+            Event::Text(text) => {
+                if self.is_in_metadata {
+                    // Front matter isn't part of the idea
+                } else if let Some(code) = &mut self.code_block {
+                    code.push_str(&text);
+                } else {
+                    self.push_text(&text);
+                }
+            }
+            Event::Code(code) => {
+                let mut style = self.style.clone();
+                style.is_code = true;
+                self.pieces
+                    .push(Piece::Text(code.to_string(), style.color_spec()));
+            }
+            Event::SoftBreak => self.push_text(" "),
+            Event::HardBreak => self.pieces.push(Piece::LineBreak),
+            Event::Rule => {
+                self.flush()?;
+                let rule = "─".repeat(self.width.saturating_sub(self.prefix_len()));
+                self.write_line(&[Piece::Text(rule, dimmed())], true)?;
+                self.blank_line()?;
+            }
+            Event::TaskListMarker(is_checked) => {
+                self.push_text(if is_checked { "[x] " } else { "[ ] " })
+            }
+            // Comments like eureka's metadata and other HTML aren't shown
+            Event::Html(_) | Event::InlineHtml(_) => {}
+            Event::FootnoteReference(_) | Event::InlineMath(_) | Event::DisplayMath(_) => {}
+        }
+        Ok(())
+    }
+
+    fn start(&mut self, tag: Tag) -> io::Result<()> {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush()?;
+                self.style.heading = Some(level);
+            }
+            Tag::BlockQuote(_) => {
+                self.flush()?;
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(_) => {
+                self.flush()?;
+                self.code_block = Some(String::new());
+            }
+            Tag::List(start) => {
+                // The text of the item this list is nested in goes first
+                self.flush()?;
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush()?;
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => String::from(BULLET),
+                };
+                self.bullet = Some(bullet);
+            }
+            Tag::Emphasis => self.style.is_italic = true,
+            Tag::Strong => self.style.is_bold = true,
+            Tag::Strikethrough => self.style.is_strikethrough = true,
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                self.style.is_link = true;
+                self.link = Some((self.pieces.len(), dest_url.to_string()));
+            }
+            Tag::MetadataBlock(_) => self.is_in_metadata = true,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn end(&mut self, tag: TagEnd) -> io::Result<()> {
+        match tag {
+            TagEnd::Paragraph => {
+                self.flush()?;
+                if self.lists.is_empty() {
+                    self.blank_line()?;
+                }
+            }
+            TagEnd::Heading(_) => {
+                self.flush()?;
+                self.style.heading = None;
+                self.blank_line()?;
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush()?;
+                self.quote_depth -= 1;
+                self.blank_line()?;
+            }
+            TagEnd::CodeBlock => {
+                let code = self.code_block.take().unwrap_or_default();
+                for line in code.trim_end_matches('\n').lines() {
+                    let piece = Piece::Text(format!("{}{}", CODE_INDENT, line), code_spec());
+                    self.write_line(&[piece], true)?;
+                }
+                self.blank_line()?;
+            }
+            TagEnd::List(_) => {
+                self.flush()?;
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line()?;
+                }
+            }
+            TagEnd::Item => self.flush()?,
+            TagEnd::Emphasis => self.style.is_italic = false,
+            TagEnd::Strong => self.style.is_bold = false,
+            TagEnd::Strikethrough => self.style.is_strikethrough = false,
+            TagEnd::Link | TagEnd::Image => {
+                self.style.is_link = false;
+                if let Some((start, url)) = self.link.take() {
+                    let text = self.pieces[start..]
+                        .iter()
+                        .map(|piece| match piece {
+                            Piece::Text(text, _) => text.as_str(),
+                            Piece::LineBreak => " ",
+                        })
+                        .collect::<String>();
+                    if !url.is_empty() && text.trim() != url {
+                        self.pieces
+                            .push(Piece::Text(format!(" ({})", url), dimmed()));
+                    }
+                }
+            }
+            TagEnd::MetadataBlock(_) => self.is_in_metadata = false,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn push_text(&mut self, text: &str) {
+        self.pieces
+            .push(Piece::Text(text.to_string(), self.style.color_spec()));
+    }
+
+    /// Word wrap and write the text of the current block
+    fn flush(&mut self) -> io::Result<()> {
+        if self.pieces.is_empty() {
+            return Ok(());
+        }
+        let pieces = std::mem::take(&mut self.pieces);
+        let text_width = self
+            .width
+            .saturating_sub(self.prefix_len())
+            .max(MIN_TEXT_WIDTH);
+
+        let mut lines = vec![vec![]];
+        let mut line_len = 0;
+        for word in words(pieces) {
+            let Some(word) = word else {
+                lines.push(vec![]);
+                line_len = 0;
+                continue;
+            };
+            let word_len = word
+                .iter()
+                .map(|piece| match piece {
+                    Piece::Text(text, _) => text.chars().count(),
+                    Piece::LineBreak => 0,
+                })
+                .sum::<usize>();
+
+            if line_len > 0 && line_len + 1 + word_len > text_width {
+                lines.push(vec![]);
+                line_len = 0;
+            }
+            let line = lines.last_mut().unwrap();
+            if line_len > 0 {
+                line.push(Piece::Text(String::from(" "), ColorSpec::new()));
+                line_len += 1;
+            }
+            line.extend(word);
+            line_len += word_len;
+        }
+
+        for line in lines {
+            self.write_line(&line, false)?;
+        }
+        Ok(())
+    }
+
+    fn write_line(&mut self, pieces: &[Piece], is_preformatted: bool) -> io::Result<()> {
+        for _ in 0..self.quote_depth {
+            self.write_styled(QUOTE_BAR, &dimmed())?;
+        }
+        let list_indent = "  ".repeat(self.lists.len().saturating_sub(1));
+        let bullet = match self.bullet.take() {
+            Some(bullet) => bullet,
+            None if !self.lists.is_empty() && !is_preformatted => {
+                " ".repeat(BULLET.chars().count().max(self.number_width()))
+            }
+            None => String::new(),
+        };
+        write!(self.out, "{}{}", list_indent, bullet)?;
+
+        for piece in pieces {
+            if let Piece::Text(text, spec) = piece {
+                self.write_styled(text, spec)?;
+            }
+        }
+        writeln!(self.out)?;
+        self.is_after_blank_line = false;
+        Ok(())
+    }
+
+    fn write_styled(&mut self, text: &str, spec: &ColorSpec) -> io::Result<()> {
+        self.out.set_color(spec)?;
+        write!(self.out, "{}", text)?;
+        self.out.reset()
+    }
+
+    fn blank_line(&mut self) -> io::Result<()> {
+        if !self.is_after_blank_line {
+            writeln!(self.out)?;
+            self.is_after_blank_line = true;
+        }
+        Ok(())
+    }
+
+    fn prefix_len(&self) -> usize {
+        let list_len = if self.lists.is_empty() {
+            0
+        } else {
+            2 * (self.lists.len() - 1) + BULLET.chars().count().max(self.number_width())
+        };
+        self.quote_depth * QUOTE_BAR.chars().count() + list_len
+    }
+
+    // Width of the numbers of the innermost ordered list, like `10. `
+    fn number_width(&self) -> usize {
+        match self.lists.last() {
+            Some(Some(number)) => format!("{}. ", number.saturating_sub(1)).len(),
+            _ => 0,
+        }
+    }
+}
+
+/// Split pieces of text into words, keeping the style of every part of a
+/// word. None is a forced line break.
+fn words(pieces: Vec<Piece>) -> Vec<Option<Vec<Piece>>> {
+    let mut words = vec![];
+    let mut word: Vec<Piece> = vec![];
+    for piece in pieces {
+        match piece {
+            Piece::LineBreak => {
+                if !word.is_empty() {
+                    words.push(Some(std::mem::take(&mut word)));
+                }
+                words.push(None);
+            }
+            Piece::Text(text, spec) => {
+                let mut part = String::new();
+                for c in text.chars() {
+                    if c.is_whitespace() {
+                        if !part.is_empty() {
+                            word.push(Piece::Text(std::mem::take(&mut part), spec.clone()));
+                        }
+                        if !word.is_empty() {
+                            words.push(Some(std::mem::take(&mut word)));
+                        }
+                    } else {
+                        part.push(c);
+                    }
+                }
+                if !part.is_empty() {
+                    word.push(Piece::Text(part, spec));
+                }
+            }
+        }
+    }
+    if !word.is_empty() {
+        words.push(Some(word));
+    }
+    words
+}
+
+fn dimmed() -> ColorSpec {
+    let mut spec = ColorSpec::new();
+    spec.set_dimmed(true);
+    spec
+}
+
+fn code_spec() -> ColorSpec {
+    let mut spec = ColorSpec::new();
+    spec.set_fg(Some(Color::Yellow));
+    spec
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use crate::markdown::render;
+    use termcolor::{Ansi, NoColor};
+
+    fn render_plain(markdown: &str, width: usize) -> String {
+        let mut out = NoColor::new(vec![]);
+        render(markdown, width, &mut out).unwrap();
+        String::from_utf8(out.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_markdown__render__blocks() {
+        let markdown = "# Ideas\n\n## Faster builds\n<!-- eureka: tags=perf -->\n\n\
+                        Cache the **build graph** and see [the docs](https://example.com).\n\n\
+                        * One\n* Two\n  1. Nested\n\n\
+                        ```sh\ncargo build\n```\n\n> Quoted\n";
+
+        let actual = render_plain(markdown, 80);
+
+        assert_eq!(
+            actual,
+            "Ideas\n\nFaster builds\n\n\
+             Cache the build graph and see the docs (https://example.com).\n\n\
+             • One\n• Two\n  1. Nested\n\n\
+             \x20   cargo build\n\n│ Quoted\n\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown__render__wraps_words() {
+        let actual = render_plain(
+            "Some words that will wrap\n\n* A list item that wraps too\n",
+            20,
+        );
+
+        assert_eq!(
+            actual,
+            "Some words that will\nwrap\n\n• A list item that\n  wraps too\n\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown__render__colors() {
+        let mut out = Ansi::new(vec![]);
+
+        render("Use `cargo`", 80, &mut out).unwrap();
+
+        let actual = String::from_utf8(out.into_inner()).unwrap();
+        assert!(actual.contains("\x1b[33mcargo"));
+    }
+}
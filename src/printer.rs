@@ -1,15 +1,25 @@
 use std::io;
 use std::io::Write;
+use std::ops::Range;
+
+use terminal_size::Width;
 
 pub trait Print {
     fn print(&mut self, value: &str) -> io::Result<()>;
     fn println(&mut self, value: &str) -> io::Result<()>;
+    /// Width of the terminal that is printed to, None if it's not a terminal
+    fn terminal_width(&self) -> Option<usize> {
+        None
+    }
 }
 
 pub trait PrintColor {
     fn fts_banner(&mut self) -> io::Result<()>;
     fn input_header(&mut self, value: &str) -> io::Result<()>;
     fn error(&mut self, value: &str) -> io::Result<()>;
+    fn list_item(&mut self, date: &str, id: &str, title: &str, tags: &[String]) -> io::Result<()>;
+    fn search_match(&mut self, line: &str, matches: &[Range<usize>]) -> io::Result<()>;
+    fn group_header(&mut self, value: &str) -> io::Result<()>;
 }
 
 pub struct Printer<W> {
@@ -36,6 +46,10 @@ impl<W: Write> Print for Printer<W> {
     fn println(&mut self, value: &str) -> io::Result<()> {
         writeln!(self.writer, "{}", value)
     }
+
+    fn terminal_width(&self) -> Option<usize> {
+        terminal_size::terminal_size().map(|(Width(width), _)| usize::from(width))
+    }
 }
 
 impl<W: Write + termcolor::WriteColor> PrintColor for Printer<W> {
@@ -83,16 +97,76 @@ to begin writing down ideas.
         self.println_styled(value, opts)?;
         self.writer.flush()
     }
+
+    fn list_item(&mut self, date: &str, id: &str, title: &str, tags: &[String]) -> io::Result<()> {
+        let date_opts = PrintOptions {
+            color: termcolor::Color::Blue,
+            is_bold: false,
+        };
+        let id_opts = PrintOptions {
+            color: termcolor::Color::Yellow,
+            is_bold: false,
+        };
+        let tag_opts = PrintOptions {
+            color: termcolor::Color::Cyan,
+            is_bold: false,
+        };
+
+        self.print_styled(date, date_opts)?;
+        self.print("  ")?;
+        self.print_styled(id, id_opts)?;
+        self.print("  ")?;
+        self.print(title)?;
+        if !tags.is_empty() {
+            self.print("  ")?;
+            self.print_styled(&format!("[{}]", tags.join(", ")), tag_opts)?;
+        }
+        self.println("")
+    }
+
+    fn search_match(&mut self, line: &str, matches: &[Range<usize>]) -> io::Result<()> {
+        let opts = PrintOptions {
+            color: termcolor::Color::Red,
+            is_bold: true,
+        };
+
+        self.print("    ")?;
+        let mut printed = 0;
+        for range in matches {
+            self.print(&line[printed..range.start])?;
+            self.print_styled(&line[range.clone()], opts)?;
+            printed = range.end;
+        }
+        self.println(&line[printed..])
+    }
+
+    fn group_header(&mut self, value: &str) -> io::Result<()> {
+        let opts = PrintOptions {
+            color: termcolor::Color::Magenta,
+            is_bold: true,
+        };
+        self.println_styled(value, opts)
+    }
 }
 
 impl<W: Write + termcolor::WriteColor> Printer<W> {
     fn println_styled(&mut self, value: &str, opts: PrintOptions) -> io::Result<()> {
-        let mut color_spec = termcolor::ColorSpec::new();
-        color_spec.set_fg(Some(opts.color)).set_bold(opts.is_bold);
-        self.writer.set_color(&color_spec)?;
+        self.set_style(opts)?;
         writeln!(self.writer, "{}", value)?;
         self.writer.reset()
     }
+
+    fn print_styled(&mut self, value: &str, opts: PrintOptions) -> io::Result<()> {
+        self.set_style(opts)?;
+        write!(self.writer, "{}", value)?;
+        self.writer.reset()
+    }
+
+    fn set_style(&mut self, opts: PrintOptions) -> io::Result<()> {
+        let mut color_spec = termcolor::ColorSpec::new();
+        color_spec.set_fg(Some(opts.color)).set_bold(opts.is_bold);
+        self.writer.set_color(&color_spec)
+    }
 }
 
 #[allow(non_snake_case)]
@@ -182,6 +256,54 @@ to begin writing down ideas.";
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_printer__list_item__success() {
+        let mut output = termcolor::Ansi::new(vec![]);
+        let mut printer = Printer::new(&mut output);
+
+        printer
+            .list_item(
+                "2026-10-18",
+                "3",
+                "some-title",
+                &[String::from("rust"), String::from("cli")],
+            )
+            .unwrap();
+
+        let actual = String::from_utf8(output.into_inner()).unwrap();
+        let expected = "\u{1b}[0m\u{1b}[34m2026-10-18\u{1b}[0m  \u{1b}[0m\u{1b}[33m3\u{1b}[0m  some-title  \u{1b}[0m\u{1b}[36m[rust, cli]\u{1b}[0m\n";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_printer__search_match__success() {
+        let mut output = termcolor::Ansi::new(vec![]);
+        let mut printer = Printer::new(&mut output);
+
+        printer
+            .search_match("cache the cache", &[0..5, 10..15])
+            .unwrap();
+
+        let actual = String::from_utf8(output.into_inner()).unwrap();
+        let expected = "    \u{1b}[0m\u{1b}[1m\u{1b}[31mcache\u{1b}[0m the \u{1b}[0m\u{1b}[1m\u{1b}[31mcache\u{1b}[0m\n";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_printer__group_header__success() {
+        let mut output = termcolor::Ansi::new(vec![]);
+        let mut printer = Printer::new(&mut output);
+
+        printer.group_header("doing (2)").unwrap();
+
+        let actual = String::from_utf8(output.into_inner()).unwrap();
+        let expected = "\u{1b}[0m\u{1b}[1m\u{1b}[35mdoing (2)\n\u{1b}[0m";
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_printer__println_styled__success() {
         let mut output_1 = termcolor::Ansi::new(vec![]);
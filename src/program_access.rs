@@ -1,35 +1,120 @@
 use std::io::ErrorKind;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::{env, fs, io};
 
 pub trait ProgramOpener {
     fn open_editor(&self, file_path: &str) -> io::Result<()>;
+    /// Open the editor with the cursor on `line`, starting at 1
+    fn open_editor_at(&self, file_path: &str, _line: usize) -> io::Result<()> {
+        self.open_editor(file_path)
+    }
     fn open_pager(&self, file_path: &str) -> io::Result<()>;
 }
 
+// Checked in this order, like git does
+const EDITOR_ENV_VARS: [&str; 2] = ["VISUAL", "EDITOR"];
+
 #[derive(Default)]
-pub struct ProgramAccess;
+pub struct ProgramAccess {
+    // Commands to use instead of the ones in the environment
+    editor: Option<String>,
+    pager: Option<String>,
+}
 
 impl ProgramOpener for ProgramAccess {
     fn open_editor(&self, file_path: &str) -> io::Result<()> {
-        self.open_with_fallback(file_path, "EDITOR", "vi")
+        let editor = self.editor()?;
+        self.run(&editor, file_path, vec![file_path.to_string()], &[])
+    }
+
+    fn open_editor_at(&self, file_path: &str, line: usize) -> io::Result<()> {
+        let editor = self.editor()?;
+        let args = line_args(&editor[0], file_path, line);
+        self.run(&editor, file_path, args, &[])
     }
 
     fn open_pager(&self, file_path: &str) -> io::Result<()> {
-        self.open_with_fallback(file_path, "PAGER", "less")
+        match &self.pager {
+            Some(pager) => self.run(
+                &parse_command(pager)?,
+                file_path,
+                vec![file_path.to_string()],
+                &pager_env(),
+            ),
+            None => self.open_with_fallback(file_path, "PAGER", "less"),
+        }
     }
 }
 
 impl ProgramAccess {
+    pub fn with_editor(mut self, editor: Option<String>) -> Self {
+        self.editor = editor;
+        self
+    }
+
+    pub fn with_pager(mut self, pager: Option<String>) -> Self {
+        self.pager = pager;
+        self
+    }
+
+    fn editor(&self) -> io::Result<Vec<String>> {
+        match &self.editor {
+            Some(editor) => parse_command(editor),
+            None => self.command_from_env(&EDITOR_ENV_VARS, "vi"),
+        }
+    }
+
+    /// Page `file_path` with the command in `env_var`, or else `fallback`
     fn open_with_fallback(&self, file_path: &str, env_var: &str, fallback: &str) -> io::Result<()> {
-        let program = env::var(env_var)
-            .map(PathBuf::from)
-            .or_else(|_| self.get_if_available(fallback))?;
+        let program = self.command_from_env(&[env_var], fallback)?;
+        self.run(
+            &program,
+            file_path,
+            vec![file_path.to_string()],
+            &pager_env(),
+        )
+    }
+
+    /// The command in the first of `env_vars` that is set, or else `fallback`
+    /// if it's installed
+    fn command_from_env(&self, env_vars: &[&str], fallback: &str) -> io::Result<Vec<String>> {
+        match env_vars.iter().find_map(|env_var| {
+            env::var(env_var)
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+        }) {
+            Some(value) => parse_command(&value),
+            None => self
+                .get_if_available(fallback)
+                .map(|program| vec![program.display().to_string()]),
+        }
+    }
 
+    fn run(
+        &self,
+        command: &[String],
+        file_path: &str,
+        args: Vec<String>,
+        envs: &[(&str, &str)],
+    ) -> io::Result<()> {
         // Make sure file exists
         fs::metadata(file_path)?;
-        Command::new(program).arg(file_path).status().map(|_| ())
+        let status = Command::new(&command[0])
+            .args(&command[1..])
+            .args(args)
+            .envs(envs.iter().copied())
+            .status()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(io::Error::other(format!(
+                "{} exited with {}",
+                command.join(" "),
+                status
+            )))
+        }
     }
 
     fn get_if_available(&self, program: &str) -> io::Result<PathBuf> {
@@ -37,10 +122,62 @@ impl ProgramAccess {
     }
 }
 
+/// Extra environment for the pager. Lets less show the colors of rendered
+/// ideas, like git does, unless `$LESS` is set already
+fn pager_env() -> Vec<(&'static str, &'static str)> {
+    match env::var_os("LESS") {
+        Some(_) => vec![],
+        None => vec![("LESS", "R")],
+    }
+}
+
+/// Find the program a command like `code --wait` runs on the `$PATH`
+pub fn find_program(command: &str) -> io::Result<PathBuf> {
+    let command = parse_command(command)?;
+    which::which(&command[0]).map_err(|_| {
+        io::Error::new(
+            ErrorKind::NotFound,
+            format!("Could not find {} on your $PATH", command[0]),
+        )
+    })
+}
+
+/// Split a command like `code --wait` into the program and its arguments,
+/// the way a shell would
+fn parse_command(value: &str) -> io::Result<Vec<String>> {
+    match shlex::split(value) {
+        Some(command) if !command.is_empty() => Ok(command),
+        _ => Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("Could not parse the command: {}", value),
+        )),
+    }
+}
+
+/// Arguments that open `file_path` with the cursor on `line`, for the editors
+/// that are known to support it
+fn line_args(program: &str, file_path: &str, line: usize) -> Vec<String> {
+    let name = Path::new(program)
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match name.as_str() {
+        "vi" | "vim" | "nvim" | "gvim" | "nano" | "emacs" | "emacsclient" | "micro" => {
+            vec![format!("+{}", line), file_path.to_string()]
+        }
+        "code" | "code-insiders" | "codium" => {
+            vec![String::from("--goto"), format!("{}:{}", file_path, line)]
+        }
+        _ => vec![file_path.to_string()],
+    }
+}
+
 #[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
-    use crate::program_access::{ProgramAccess, ProgramOpener};
+    use crate::program_access::{
+        find_program, line_args, parse_command, ProgramAccess, ProgramOpener,
+    };
     use std::env;
 
     type TestResult = Result<(), Box<dyn std::error::Error>>;
@@ -80,6 +217,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_program_access__open_with_fallback__non_zero_exit_status() -> TestResult {
+        let program_access = ProgramAccess::default();
+        let tmp_file = tempfile::NamedTempFile::new()?;
+        let file_path = tmp_file.path().to_str().unwrap();
+        env::set_var("FAILING_ENV_VAR", "false");
+
+        let actual = program_access.open_with_fallback(
+            file_path,
+            "FAILING_ENV_VAR",
+            "some-non-existing-program",
+        );
+
+        env::remove_var("FAILING_ENV_VAR");
+
+        assert!(actual.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_program_access__open_with_fallback__uses_fallback() -> TestResult {
         let program_access = ProgramAccess::default();
@@ -92,6 +248,109 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_program_access__open_with_fallback__command_with_args() -> TestResult {
+        let program_access = ProgramAccess::default();
+        let tmp_file = tempfile::NamedTempFile::new()?;
+        let file_path = tmp_file.path().to_str().unwrap();
+        env::set_var("ARGS_ENV_VAR", "echo 'opening file'");
+
+        let actual = program_access.open_with_fallback(
+            file_path,
+            "ARGS_ENV_VAR",
+            "some-non-existing-program",
+        );
+
+        env::remove_var("ARGS_ENV_VAR");
+
+        assert!(actual.is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_access__command_from_env__first_set_wins() -> TestResult {
+        let program_access = ProgramAccess::default();
+        env::set_var("FIRST_EDITOR_ENV_VAR", "emacsclient -t");
+        env::set_var("SECOND_EDITOR_ENV_VAR", "vi");
+
+        let actual = program_access.command_from_env(
+            &[
+                "UNSET_EDITOR_ENV_VAR",
+                "FIRST_EDITOR_ENV_VAR",
+                "SECOND_EDITOR_ENV_VAR",
+            ],
+            "some-non-existing-program",
+        )?;
+
+        env::remove_var("FIRST_EDITOR_ENV_VAR");
+        env::remove_var("SECOND_EDITOR_ENV_VAR");
+
+        assert_eq!(actual, vec!["emacsclient", "-t"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_access__parse_command() {
+        assert_eq!(
+            parse_command("code --wait").unwrap(),
+            vec!["code", "--wait"]
+        );
+        assert_eq!(
+            parse_command("'/Applications/My Editor' -w").unwrap(),
+            vec!["/Applications/My Editor", "-w"]
+        );
+        assert!(parse_command("vim 'unclosed").is_err());
+    }
+
+    #[test]
+    fn test_program_access__line_args() {
+        assert_eq!(
+            line_args("/usr/bin/nvim", "README.md", 7),
+            vec!["+7", "README.md"]
+        );
+        assert_eq!(line_args("nano", "README.md", 7), vec!["+7", "README.md"]);
+        assert_eq!(
+            line_args("code", "README.md", 7),
+            vec!["--goto", "README.md:7"]
+        );
+        assert_eq!(line_args("some-editor", "README.md", 7), vec!["README.md"]);
+    }
+
+    #[test]
+    fn test_program_access__find_program() {
+        assert!(find_program("echo 'some argument'").is_ok());
+        assert!(find_program("some-non-existing-program --wait").is_err());
+    }
+
+    #[test]
+    fn test_program_access__open_editor__configured_editor_wins() -> TestResult {
+        let program_access = ProgramAccess::default().with_editor(Some(String::from("true")));
+        let tmp_file = tempfile::NamedTempFile::new()?;
+        let file_path = tmp_file.path().to_str().unwrap();
+
+        // $VISUAL and $EDITOR are not used, so this doesn't depend on them
+        program_access.open_editor_at(file_path, 3)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_access__less_colors_only_for_the_pager() -> TestResult {
+        if env::var_os("LESS").is_some() {
+            return Ok(());
+        }
+        let program_access = ProgramAccess::default()
+            .with_editor(Some(String::from("sh -c 'test -z \"$LESS\"'")))
+            .with_pager(Some(String::from("sh -c 'test \"$LESS\" = R'")));
+        let tmp_file = tempfile::NamedTempFile::new()?;
+        let file_path = tmp_file.path().to_str().unwrap();
+
+        program_access.open_editor(file_path)?;
+        program_access.open_pager(file_path)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_access__open_editor__success() -> TestResult {
         let program_access = ProgramAccess::default();
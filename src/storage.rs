@@ -0,0 +1,1002 @@
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use chrono::{DateTime, Local};
+
+use crate::idea::{Idea, IdeaDocument, IdeaFormat, IdeaStatus};
+
+pub const IDEA_FILE_NAME: &str = "README.md";
+pub const IDEAS_DIR_NAME: &str = "ideas";
+pub const ARCHIVE_FILE_NAME: &str = "ARCHIVE.md";
+pub const ARCHIVE_DIR_NAME: &str = "archive";
+
+// Where new ideas go in README.md when the position is `marker`
+pub const NEW_IDEAS_MARKER: &str = "<!-- eureka:new-ideas -->";
+
+const INDEX_START: &str = "<!-- eureka:index:start -->";
+const INDEX_END: &str = "<!-- eureka:index:end -->";
+const MAX_SLUG_LEN: usize = 50;
+const IDEA_ID_LEN: usize = 7;
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum StorageLayout {
+    // All ideas are sections in README.md
+    #[default]
+    SingleFile,
+
+    // Every idea is its own file in ideas/, README.md holds an index
+    FilePerIdea,
+}
+
+impl FromStr for StorageLayout {
+    type Err = io::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "single-file" => Ok(StorageLayout::SingleFile),
+            "file-per-idea" => Ok(StorageLayout::FilePerIdea),
+            _ => Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Unknown storage layout: {}, use single-file or file-per-idea",
+                    value
+                ),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for StorageLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageLayout::SingleFile => write!(f, "single-file"),
+            StorageLayout::FilePerIdea => write!(f, "file-per-idea"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum IdeaPosition {
+    // Before the first idea
+    Top,
+
+    // After the last idea
+    #[default]
+    Bottom,
+
+    // Right below the new ideas marker
+    Marker,
+}
+
+impl FromStr for IdeaPosition {
+    type Err = io::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "top" => Ok(IdeaPosition::Top),
+            "bottom" => Ok(IdeaPosition::Bottom),
+            "marker" => Ok(IdeaPosition::Marker),
+            _ => Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Unknown idea position: {}, use top, bottom or marker",
+                    value
+                ),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for IdeaPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdeaPosition::Top => write!(f, "top"),
+            IdeaPosition::Bottom => write!(f, "bottom"),
+            IdeaPosition::Marker => write!(f, "marker"),
+        }
+    }
+}
+
+/// A new idea written for the user to fill in
+#[derive(Debug)]
+pub struct Draft {
+    pub path: PathBuf,
+    // Line the cursor should be put on, starting at 1
+    pub line: usize,
+    // What the file held before the idea was written into it, if it existed
+    original: Option<String>,
+}
+
+/// Where and how ideas are written in the idea repo. Paths returned
+/// from here are relative to the repo root so they can be staged as is.
+pub struct Storage {
+    layout: StorageLayout,
+    position: IdeaPosition,
+    // The single idea file, or the index with the file per idea layout
+    file: PathBuf,
+    repo: PathBuf,
+}
+
+impl Storage {
+    pub fn new(repo_path: &str, layout: StorageLayout) -> Self {
+        Storage {
+            layout,
+            position: IdeaPosition::default(),
+            file: PathBuf::from(IDEA_FILE_NAME),
+            repo: PathBuf::from(repo_path),
+        }
+    }
+
+    /// Where new ideas are put in README.md, only used by the single file layout
+    pub fn with_position(mut self, position: IdeaPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Keep ideas in `file` instead of README.md
+    pub fn with_file(mut self, file: PathBuf) -> Self {
+        self.file = file;
+        self
+    }
+
+    pub fn layout(&self) -> StorageLayout {
+        self.layout
+    }
+
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+
+    pub fn absolute(&self, path: &Path) -> PathBuf {
+        self.repo.join(path)
+    }
+
+    /// Write a new idea for the user to fill in. For a single file that's a
+    /// section in README.md, otherwise a new idea file.
+    pub fn prepare_idea(
+        &self,
+        summary: &str,
+        body: &str,
+        tags: &[String],
+        author: Option<String>,
+        created: DateTime<Local>,
+    ) -> io::Result<Draft> {
+        match self.layout {
+            StorageLayout::SingleFile => {
+                let idea_path = self.file.clone();
+                let mut idea = new_idea(
+                    IdeaFormat::Section,
+                    &idea_path,
+                    summary,
+                    tags,
+                    author,
+                    created,
+                );
+                let head_len = idea.to_string().lines().count();
+                idea.set_body(body);
+
+                let mut section = idea.to_string();
+                if idea.body.is_empty() {
+                    // Leave an empty line to write the idea on
+                    section.push('\n');
+                }
+
+                let file_path = self.absolute(&idea_path);
+                let original = read_if_exists(&file_path)?;
+                let (contents, start) =
+                    self.insert_section(original.as_deref().unwrap_or_default(), &section)?;
+                fs::write(&file_path, contents)?;
+
+                Ok(Draft {
+                    path: idea_path,
+                    line: start + head_len + usize::from(!idea.body.is_empty()),
+                    original,
+                })
+            }
+            StorageLayout::FilePerIdea => {
+                let idea_path = self.new_idea_path(summary, created)?;
+                let mut idea = new_idea(
+                    IdeaFormat::FrontMatter,
+                    &idea_path,
+                    summary,
+                    tags,
+                    author,
+                    created,
+                );
+                let head_len = idea.to_string().lines().count();
+                idea.set_body(body);
+
+                let mut contents = idea.to_string();
+                if idea.body.is_empty() {
+                    contents.push('\n');
+                }
+                self.create_idea_file(&idea_path, &contents)?;
+
+                // Right below the front matter
+                Ok(Draft {
+                    path: idea_path,
+                    line: head_len + 1 + usize::from(!idea.body.is_empty()),
+                    original: None,
+                })
+            }
+        }
+    }
+
+    /// Store an idea without involving the user
+    pub fn write_idea(
+        &self,
+        summary: &str,
+        body: &str,
+        tags: &[String],
+        author: Option<String>,
+        created: DateTime<Local>,
+    ) -> io::Result<PathBuf> {
+        match self.layout {
+            StorageLayout::SingleFile => {
+                let idea_path = self.file.clone();
+                let mut idea = new_idea(
+                    IdeaFormat::Section,
+                    &idea_path,
+                    summary,
+                    tags,
+                    author,
+                    created,
+                );
+                idea.set_body(body);
+
+                let file_path = self.absolute(&idea_path);
+                let original = read_if_exists(&file_path)?.unwrap_or_default();
+                let (contents, _) = self.insert_section(&original, &idea.to_string())?;
+                fs::write(&file_path, contents)?;
+                Ok(idea_path)
+            }
+            StorageLayout::FilePerIdea => {
+                let idea_path = self.new_idea_path(summary, created)?;
+                let mut idea = new_idea(
+                    IdeaFormat::FrontMatter,
+                    &idea_path,
+                    summary,
+                    tags,
+                    author,
+                    created,
+                );
+                idea.set_body(body);
+                self.create_idea_file(&idea_path, &idea.to_string())?;
+                Ok(idea_path)
+            }
+        }
+    }
+
+    /// Undo a prepared idea that won't be committed
+    pub fn discard(&self, draft: &Draft) -> io::Result<()> {
+        let file_path = self.absolute(&draft.path);
+        match &draft.original {
+            Some(original) => fs::write(file_path, original),
+            None => fs::remove_file(file_path),
+        }
+    }
+
+    /// Bring the rest of the repo in line with a new or changed idea and
+    /// return every path that needs to be committed
+    pub fn finish(&self, idea_path: &Path) -> io::Result<Vec<PathBuf>> {
+        match self.layout {
+            StorageLayout::SingleFile => Ok(vec![idea_path.to_path_buf()]),
+            StorageLayout::FilePerIdea => {
+                self.update_index()?;
+                Ok(vec![idea_path.to_path_buf(), self.file.clone()])
+            }
+        }
+    }
+
+    /// Read and parse every file that holds ideas
+    pub fn documents(&self) -> io::Result<Vec<IdeaDocument>> {
+        match self.layout {
+            StorageLayout::SingleFile => {
+                let path = self.file.clone();
+                let contents = fs::read_to_string(self.absolute(&path))?;
+                Ok(vec![IdeaDocument::parse_single_file(&path, &contents)])
+            }
+            StorageLayout::FilePerIdea => {
+                let ideas_dir = self.absolute(Path::new(IDEAS_DIR_NAME));
+                let mut file_names = match fs::read_dir(&ideas_dir) {
+                    Ok(entries) => entries
+                        .map(|entry| entry.map(|entry| entry.file_name()))
+                        .collect::<io::Result<Vec<_>>>()?,
+                    Err(err) if err.kind() == ErrorKind::NotFound => vec![],
+                    Err(err) => return Err(err),
+                };
+                file_names
+                    .retain(|file_name| Path::new(file_name).extension() == Some(OsStr::new("md")));
+                // Idea files start with their date, so this is oldest first
+                file_names.sort();
+
+                file_names
+                    .into_iter()
+                    .map(|file_name| {
+                        let path = PathBuf::from(IDEAS_DIR_NAME).join(file_name);
+                        let contents = fs::read_to_string(self.absolute(&path))?;
+                        Ok(IdeaDocument::parse_idea_file(&path, &contents))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Every idea in the repo, in the order they are stored
+    pub fn ideas(&self) -> io::Result<Vec<Idea>> {
+        let mut ideas = self
+            .documents()?
+            .into_iter()
+            .flat_map(|document| document.ideas)
+            .collect::<Vec<Idea>>();
+        let mut seen = HashMap::new();
+        for idea in ideas.iter_mut() {
+            idea.id = idea_id(idea, &mut seen)?;
+        }
+        Ok(ideas)
+    }
+
+    /// Write a document back to the file it was read from
+    pub fn save(&self, document: &IdeaDocument) -> io::Result<()> {
+        fs::write(self.absolute(&document.path), document.to_string())
+    }
+
+    /// Replace an idea with the edited text and return every path that
+    /// needs to be committed
+    pub fn replace(&self, idea: &Idea, text: &str) -> io::Result<Vec<PathBuf>> {
+        let mut document = self
+            .documents()?
+            .into_iter()
+            .find(|document| document.path == idea.path)
+            .ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::NotFound,
+                    format!("Could not find {}", idea.path.display()),
+                )
+            })?;
+
+        if !document.replace_idea(idea.line, text) {
+            return Err(io::Error::new(
+                ErrorKind::NotFound,
+                format!(
+                    "Could not find idea #{} in {}",
+                    idea.id,
+                    idea.path.display()
+                ),
+            ));
+        }
+        self.save(&document)?;
+
+        self.finish(&idea.path)
+    }
+
+    /// Delete an idea and return every path that needs to be committed
+    pub fn remove(&self, idea: &Idea) -> io::Result<Vec<PathBuf>> {
+        match self.layout {
+            StorageLayout::SingleFile => self.replace(idea, ""),
+            StorageLayout::FilePerIdea => {
+                fs::remove_file(self.absolute(&idea.path))?;
+                self.finish(&idea.path)
+            }
+        }
+    }
+
+    /// Move an idea to ARCHIVE.md, or to archive/ for the file-per-idea
+    /// layout, and return every path that needs to be committed
+    pub fn archive(&self, idea: &Idea) -> io::Result<Vec<PathBuf>> {
+        let archive_path = match self.layout {
+            StorageLayout::SingleFile => {
+                let archive_path = PathBuf::from(ARCHIVE_FILE_NAME);
+                append_archived(&self.absolute(&archive_path), &idea.to_string())?;
+                archive_path
+            }
+            StorageLayout::FilePerIdea => {
+                let file_name = idea.path.file_name().unwrap_or_default();
+                let archive_path = PathBuf::from(ARCHIVE_DIR_NAME).join(file_name);
+                if self.absolute(&archive_path).exists() {
+                    return Err(io::Error::new(
+                        ErrorKind::AlreadyExists,
+                        format!("{} already exists", archive_path.display()),
+                    ));
+                }
+                fs::create_dir_all(self.absolute(Path::new(ARCHIVE_DIR_NAME)))?;
+                fs::copy(self.absolute(&idea.path), self.absolute(&archive_path))?;
+                archive_path
+            }
+        };
+
+        let mut paths = self.remove(idea)?;
+        paths.push(archive_path);
+        Ok(paths)
+    }
+
+    /// Regenerate the index of idea files in README.md, newest first.
+    /// Anything outside of the index markers is left as is.
+    pub fn update_index(&self) -> io::Result<()> {
+        let mut ideas = self.ideas()?;
+        ideas.sort_by(|a, b| b.created.cmp(&a.created).then_with(|| b.path.cmp(&a.path)));
+
+        let mut index = String::from(INDEX_START);
+        index.push('\n');
+        for idea in &ideas {
+            index.push_str(&index_line(idea));
+            index.push('\n');
+        }
+        index.push_str(INDEX_END);
+
+        let readme_path = self.absolute(&self.file);
+        let readme = match fs::read_to_string(&readme_path) {
+            Ok(readme) => readme,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::from("# Ideas\n"),
+            Err(err) => return Err(err),
+        };
+
+        let updated = match (readme.find(INDEX_START), readme.find(INDEX_END)) {
+            (Some(start), Some(end)) if start < end => format!(
+                "{}{}{}",
+                &readme[..start],
+                index,
+                &readme[end + INDEX_END.len()..]
+            ),
+            _ => {
+                let separator = if readme.ends_with('\n') { "\n" } else { "\n\n" };
+                format!("{}{}{}\n", readme, separator, index)
+            }
+        };
+
+        fs::write(readme_path, updated)
+    }
+
+    /// Put a new idea section in README.md at the configured position
+    fn insert_section(&self, contents: &str, section: &str) -> io::Result<(String, usize)> {
+        let line_count = contents.split_inclusive('\n').count();
+        let at = match self.position {
+            IdeaPosition::Bottom => line_count,
+            IdeaPosition::Top => IdeaDocument::parse_single_file(&self.file, contents)
+                .ideas
+                .first()
+                .map_or(line_count, |idea| idea.line - 1),
+            IdeaPosition::Marker => {
+                let marker = contents
+                    .lines()
+                    .position(|line| line.trim() == NEW_IDEAS_MARKER)
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            ErrorKind::InvalidInput,
+                            format!(
+                                "New ideas go below the marker, but {} has no {} line. Add it where new ideas should go.",
+                                self.file.display(),
+                                NEW_IDEAS_MARKER
+                            ),
+                        )
+                    })?;
+                marker + 1
+            }
+        };
+
+        Ok(insert_at_line(contents, at, section))
+    }
+
+    /// A free path in ideas/ for a new idea, named after its date and summary
+    fn new_idea_path(&self, summary: &str, created: DateTime<Local>) -> io::Result<PathBuf> {
+        let ideas_dir = self.absolute(Path::new(IDEAS_DIR_NAME));
+        fs::create_dir_all(&ideas_dir)?;
+
+        let stem = format!("{}-{}", created.format("%Y-%m-%d"), slugify(summary));
+        let mut idea_path = PathBuf::from(IDEAS_DIR_NAME).join(format!("{}.md", stem));
+        let mut suffix = 2;
+        while self.absolute(&idea_path).exists() {
+            idea_path = PathBuf::from(IDEAS_DIR_NAME).join(format!("{}-{}.md", stem, suffix));
+            suffix += 1;
+        }
+        Ok(idea_path)
+    }
+
+    fn create_idea_file(&self, idea_path: &Path, contents: &str) -> io::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(self.absolute(idea_path))?;
+        write!(file, "{}", contents)
+    }
+}
+
+/// A new idea with everything eureka knows about it, idea files also get a status
+fn new_idea(
+    format: IdeaFormat,
+    path: &Path,
+    summary: &str,
+    tags: &[String],
+    author: Option<String>,
+    created: DateTime<Local>,
+) -> Idea {
+    let mut idea = Idea::new(format, path, summary);
+    idea.created = Some(created.fixed_offset());
+    idea.author = author;
+    idea.tags = tags.to_vec();
+    if format == IdeaFormat::FrontMatter {
+        idea.status = Some(IdeaStatus::New.to_string());
+    }
+    idea
+}
+
+fn index_line(idea: &Idea) -> String {
+    let title = idea.title.replace('[', "\\[").replace(']', "\\]");
+    let link = idea.path.to_string_lossy().replace('\\', "/");
+    match idea.created {
+        Some(created) => format!("- [{}]({}) - {}", title, link, created.format("%Y-%m-%d")),
+        None => format!("- [{}]({})", title, link),
+    }
+}
+
+/// A short hash of the file an idea lives in and when it was created, or its
+/// title if it has no creation date. Unlike its position it doesn't change
+/// when other ideas are added or removed. Ideas that would hash the same are
+/// told apart by the order they are stored in.
+fn idea_id(idea: &Idea, seen: &mut HashMap<String, usize>) -> io::Result<String> {
+    let mut key = match idea.created {
+        Some(created) => format!("{}\n{}", idea.path.display(), created.to_rfc3339()),
+        None => format!("{}\n{}", idea.path.display(), idea.title),
+    };
+    let count = seen.entry(key.clone()).or_insert(0);
+    *count += 1;
+    if *count > 1 {
+        key.push_str(&format!("\n{}", count));
+    }
+
+    let oid =
+        git2::Oid::hash_object(git2::ObjectType::Blob, key.as_bytes()).map_err(io::Error::other)?;
+    Ok(oid.to_string()[..IDEA_ID_LEN].to_string())
+}
+
+fn slugify(summary: &str) -> String {
+    let mut slug = String::new();
+    for c in summary.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    let slug: String = slug.chars().take(MAX_SLUG_LEN).collect();
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        String::from("idea")
+    } else {
+        slug.to_string()
+    }
+}
+
+fn read_if_exists(file_path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(file_path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Put `section` before line `at` (starting at 0) of `contents`, with an
+/// empty line around it. Returns the new contents and the line the section
+/// starts at, starting at 1.
+fn insert_at_line(contents: &str, at: usize, section: &str) -> (String, usize) {
+    let offset = contents
+        .split_inclusive('\n')
+        .take(at)
+        .map(str::len)
+        .sum::<usize>();
+    let (before, after) = contents.split_at(offset);
+
+    let mut updated = before.to_string();
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    if !updated.is_empty() && !updated.ends_with("\n\n") {
+        updated.push('\n');
+    }
+    let start = updated.lines().count() + 1;
+
+    updated.push_str(section);
+    if !after.trim().is_empty() {
+        if !updated.ends_with("\n\n") && !after.starts_with('\n') {
+            updated.push('\n');
+        }
+        updated.push_str(after);
+    }
+
+    (updated, start)
+}
+
+/// Append an idea to the archive file, creating it if needed
+fn append_archived(archive_path: &Path, text: &str) -> io::Result<()> {
+    let mut contents = match fs::read_to_string(archive_path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == ErrorKind::NotFound => String::from("# Archived ideas\n"),
+        Err(err) => return Err(err),
+    };
+
+    if !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    if !contents.ends_with("\n\n") {
+        contents.push('\n');
+    }
+    contents.push_str(text.trim_end());
+    contents.push('\n');
+
+    fs::write(archive_path, contents)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use crate::storage::{slugify, IdeaPosition, Storage, StorageLayout};
+    use chrono::{Local, TimeZone};
+    use std::path::{Path, PathBuf};
+    use std::{fs, io};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_storage__slugify() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("  Ünïcode  ideas "), "ünïcode-ideas");
+        assert_eq!(slugify("???"), "idea");
+    }
+
+    #[test]
+    fn test_storage__write_idea__single_file() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("README.md"), "# Ideas\n").unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::SingleFile);
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+
+        let idea_path = storage
+            .write_idea(
+                "Summary",
+                "Body",
+                &[String::from("perf")],
+                Some(String::from("Some Name")),
+                created,
+            )
+            .unwrap();
+        let paths = storage.finish(&idea_path).unwrap();
+
+        let contents = fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(
+            contents,
+            format!(
+                "# Ideas\n\n## Summary\n<!-- eureka: created={}; tags=perf; author=Some Name -->\n\nBody\n",
+                created.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+            )
+        );
+        assert_eq!(paths, vec![PathBuf::from("README.md")]);
+    }
+
+    #[test]
+    fn test_storage__write_idea__single_file__other_file() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("README.md"), "# Project\n").unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::SingleFile)
+            .with_file(PathBuf::from("IDEAS.md"));
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+
+        let idea_path = storage
+            .write_idea("Summary", "Body", &[], None, created)
+            .unwrap();
+        let ideas = storage.ideas().unwrap();
+
+        let contents = fs::read_to_string(dir.path().join("IDEAS.md")).unwrap();
+        let readme = fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(idea_path, PathBuf::from("IDEAS.md"));
+        assert_eq!(
+            contents,
+            format!(
+                "## Summary\n<!-- eureka: created={} -->\n\nBody\n",
+                created.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+            )
+        );
+        assert_eq!(readme, "# Project\n");
+        assert_eq!(ideas[0].path, PathBuf::from("IDEAS.md"));
+    }
+
+    #[test]
+    fn test_storage__prepare_idea__single_file() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("README.md"), "# Ideas\n\n## Older\n").unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::SingleFile);
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+
+        let draft = storage
+            .prepare_idea(
+                "Summary",
+                "",
+                &[String::from("perf")],
+                Some(String::from("Some Name")),
+                created,
+            )
+            .unwrap();
+
+        let contents = fs::read_to_string(dir.path().join(&draft.path)).unwrap();
+        assert_eq!(
+            contents,
+            format!(
+                "# Ideas\n\n## Older\n\n## Summary\n<!-- eureka: created={}; tags=perf; author=Some Name -->\n\n",
+                created.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+            )
+        );
+        assert_eq!(draft.line, 7);
+
+        storage.discard(&draft).unwrap();
+        let contents = fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(contents, "# Ideas\n\n## Older\n");
+    }
+
+    #[test]
+    fn test_storage__prepare_idea__single_file__top() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("README.md"), "# Ideas\n\n## Older\n").unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::SingleFile)
+            .with_position(IdeaPosition::Top);
+
+        let draft = storage
+            .prepare_idea("Summary", "Body", &[], None, Local::now())
+            .unwrap();
+
+        let contents = fs::read_to_string(dir.path().join(&draft.path)).unwrap();
+        let lines = contents.lines().collect::<Vec<_>>();
+        assert_eq!(lines[2], "## Summary");
+        assert_eq!(lines[draft.line - 1], "Body");
+        assert!(contents.ends_with("\nBody\n\n## Older\n"));
+    }
+
+    #[test]
+    fn test_storage__prepare_idea__single_file__marker() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("README.md"),
+            "# Ideas\n\n## Pinned\n\n<!-- eureka:new-ideas -->\n## Older\n",
+        )
+        .unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::SingleFile)
+            .with_position(IdeaPosition::Marker);
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+
+        storage
+            .write_idea("Summary", "Body", &[], None, created)
+            .unwrap();
+
+        let contents = fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(
+            contents,
+            format!(
+                "# Ideas\n\n## Pinned\n\n<!-- eureka:new-ideas -->\n\n## Summary\n<!-- eureka: created={} -->\n\nBody\n\n## Older\n",
+                created.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+            )
+        );
+    }
+
+    #[test]
+    fn test_storage__prepare_idea__single_file__marker_missing() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("README.md"), "# Ideas\n").unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::SingleFile)
+            .with_position(IdeaPosition::Marker);
+
+        let actual = storage.prepare_idea("Summary", "", &[], None, Local::now());
+
+        assert_eq!(actual.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        let contents = fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(contents, "# Ideas\n");
+    }
+
+    #[test]
+    fn test_storage__write_idea__file_per_idea() {
+        let dir = TempDir::new().unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::FilePerIdea);
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+
+        let idea_path = storage
+            .write_idea(
+                "A \"quoted\" idea",
+                "Body",
+                &[String::from("rust")],
+                None,
+                created,
+            )
+            .unwrap();
+        let paths = storage.finish(&idea_path).unwrap();
+
+        assert_eq!(
+            idea_path,
+            Path::new("ideas").join("2026-10-18-a-quoted-idea.md")
+        );
+        let contents = fs::read_to_string(dir.path().join(&idea_path)).unwrap();
+        assert_eq!(
+            contents,
+            format!(
+                "---\ntitle: \"A \\\"quoted\\\" idea\"\ncreated: {}\ntags: [rust]\nstatus: new\n---\n\nBody\n",
+                created.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+            )
+        );
+        assert_eq!(paths, vec![idea_path, PathBuf::from("README.md")]);
+    }
+
+    #[test]
+    fn test_storage__write_idea__file_per_idea__same_summary() {
+        let dir = TempDir::new().unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::FilePerIdea);
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+
+        let first = storage.write_idea("Idea", "", &[], None, created).unwrap();
+        let second = storage.write_idea("Idea", "", &[], None, created).unwrap();
+
+        assert_eq!(first, Path::new("ideas").join("2026-10-18-idea.md"));
+        assert_eq!(second, Path::new("ideas").join("2026-10-18-idea-2.md"));
+    }
+
+    #[test]
+    fn test_storage__prepare_idea__file_per_idea() {
+        let dir = TempDir::new().unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::FilePerIdea);
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+
+        let empty = storage
+            .prepare_idea("Empty", "", &[], Some(String::from("Some Name")), created)
+            .unwrap();
+        let filled = storage
+            .prepare_idea("Filled", "Body", &[], None, created)
+            .unwrap();
+
+        let empty_contents = fs::read_to_string(dir.path().join(&empty.path)).unwrap();
+        let filled_contents = fs::read_to_string(dir.path().join(&filled.path)).unwrap();
+        assert_eq!(
+            empty_contents,
+            format!(
+                "---\ntitle: \"Empty\"\ncreated: {}\ntags: []\nstatus: new\nauthor: \"Some Name\"\n---\n\n",
+                created.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)
+            )
+        );
+        // Right below the front matter, on the body if there is one
+        assert_eq!(empty.line, 8);
+        assert_eq!(empty_contents.lines().nth(empty.line - 2), Some("---"));
+        assert_eq!(filled_contents.lines().nth(filled.line - 1), Some("Body"));
+    }
+
+    #[test]
+    fn test_storage__update_index__keeps_surrounding_text() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("README.md"),
+            "# My ideas\n\n<!-- eureka:index:start -->\n- stale\n<!-- eureka:index:end -->\n\nFooter\n",
+        )
+        .unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::FilePerIdea);
+
+        let older = Local.with_ymd_and_hms(2026, 10, 17, 9, 30, 0).unwrap();
+        let newer = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+        storage.write_idea("Older", "", &[], None, older).unwrap();
+        storage
+            .write_idea("Newer [draft]", "", &[], None, newer)
+            .unwrap();
+        storage.update_index().unwrap();
+
+        let contents = fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(
+            contents,
+            "# My ideas\n\n\
+             <!-- eureka:index:start -->\n\
+             - [Newer \\[draft\\]](ideas/2026-10-18-newer-draft.md) - 2026-10-18\n\
+             - [Older](ideas/2026-10-17-older.md) - 2026-10-17\n\
+             <!-- eureka:index:end -->\n\nFooter\n"
+        );
+    }
+
+    #[test]
+    fn test_storage__update_index__creates_readme() {
+        let dir = TempDir::new().unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::FilePerIdea);
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+
+        let draft = storage
+            .prepare_idea("Idea", "", &[], None, created)
+            .unwrap();
+        storage.finish(&draft.path).unwrap();
+
+        let contents = fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(
+            contents,
+            "# Ideas\n\n<!-- eureka:index:start -->\n- [Idea](ideas/2026-10-18-idea.md) - 2026-10-18\n<!-- eureka:index:end -->\n"
+        );
+    }
+
+    #[test]
+    fn test_storage__ideas__ids_survive_removal() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("README.md"),
+            "# Ideas\n\n## First\n\n## Same\n\n## Same\n",
+        )
+        .unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::SingleFile);
+        let before = storage.ideas().unwrap();
+        assert_ne!(before[1].id, before[2].id);
+
+        storage.remove(&before[0]).unwrap();
+
+        let after = storage.ideas().unwrap();
+        assert_eq!(after[0].id, before[1].id);
+        assert_eq!(after[1].id, before[2].id);
+    }
+
+    #[test]
+    fn test_storage__archive__single_file() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("README.md"),
+            "# Ideas\n\n## First\n\nBody\n\n## Second\n",
+        )
+        .unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::SingleFile);
+        let ideas = storage.ideas().unwrap();
+
+        let paths = storage.archive(&ideas[0]).unwrap();
+
+        let readme = fs::read_to_string(dir.path().join("README.md")).unwrap();
+        let archive = fs::read_to_string(dir.path().join("ARCHIVE.md")).unwrap();
+        assert_eq!(readme, "# Ideas\n\n## Second\n");
+        assert_eq!(archive, "# Archived ideas\n\n## First\n\nBody\n");
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("README.md"), PathBuf::from("ARCHIVE.md")]
+        );
+    }
+
+    #[test]
+    fn test_storage__archive__file_per_idea() {
+        let dir = TempDir::new().unwrap();
+        let storage = Storage::new(dir.path().to_str().unwrap(), StorageLayout::FilePerIdea);
+        let created = Local.with_ymd_and_hms(2026, 10, 18, 9, 30, 0).unwrap();
+        let idea_path = storage
+            .write_idea("Idea", "Body", &[], None, created)
+            .unwrap();
+        let idea = storage.ideas().unwrap().remove(0);
+
+        let paths = storage.archive(&idea).unwrap();
+
+        let archive_path = Path::new("archive").join("2026-10-18-idea.md");
+        assert!(!dir.path().join(&idea_path).exists());
+        assert!(dir.path().join(&archive_path).exists());
+        assert!(storage.ideas().unwrap().is_empty());
+        assert_eq!(
+            paths,
+            vec![idea_path, PathBuf::from("README.md"), archive_path]
+        );
+    }
+
+    #[test]
+    fn test_storage__layout__from_str() {
+        assert_eq!(
+            "file-per-idea".parse::<StorageLayout>().unwrap(),
+            StorageLayout::FilePerIdea
+        );
+        assert_eq!(
+            StorageLayout::SingleFile
+                .to_string()
+                .parse::<StorageLayout>()
+                .unwrap(),
+            StorageLayout::SingleFile
+        );
+        assert!("something-else".parse::<StorageLayout>().is_err());
+        assert_eq!(
+            "marker".parse::<IdeaPosition>().unwrap(),
+            IdeaPosition::Marker
+        );
+        assert!("middle".parse::<IdeaPosition>().is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use std::io;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use regex::{Captures, Regex};
+
+// Templates in the idea repo, relative to its root
+pub const REPO_TEMPLATES_DIR: &str = ".eureka/templates";
+// Templates in the config directory
+pub const TEMPLATES_DIR_NAME: &str = "templates";
+const TEMPLATE_EXTENSION: &str = "md";
+
+/// Values that can be used in a template as `{{name}}`
+#[derive(Debug, Default)]
+pub struct TemplateVars {
+    pub summary: String,
+    pub date: String,
+    pub author: String,
+    pub branch: String,
+}
+
+impl TemplateVars {
+    fn get(&self, name: &str) -> Option<&str> {
+        match name {
+            "summary" => Some(&self.summary),
+            "date" => Some(&self.date),
+            "author" => Some(&self.author),
+            "branch" => Some(&self.branch),
+            _ => None,
+        }
+    }
+}
+
+/// Path of the template called `name` in `templates_dir`
+pub fn template_path(templates_dir: &Path, name: &str) -> io::Result<PathBuf> {
+    let is_plain_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !name.starts_with('.');
+    if !is_plain_name {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid template name: {}", name),
+        ));
+    }
+
+    Ok(templates_dir.join(format!("{}.{}", name, TEMPLATE_EXTENSION)))
+}
+
+/// Fill in the placeholders of a template. Placeholders eureka doesn't know
+/// are left as they are.
+pub fn render(template: &str, vars: &TemplateVars) -> String {
+    let placeholder = Regex::new(r"\{\{\s*(\w+)\s*\}\}").unwrap();
+    placeholder
+        .replace_all(template, |captures: &Captures| {
+            vars.get(&captures[1])
+                .map(String::from)
+                .unwrap_or_else(|| captures[0].to_string())
+        })
+        .into_owned()
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use crate::template::{render, template_path, TemplateVars};
+    use std::path::Path;
+
+    #[test]
+    fn test_template__render() {
+        let vars = TemplateVars {
+            summary: String::from("Cache builds"),
+            date: String::from("2026-10-18"),
+            author: String::from("Some Name"),
+            branch: String::from("main"),
+        };
+
+        let actual = render(
+            "# {{summary}}\n{{ date }} by {{author}} on {{branch}}\n{{unknown}}\n",
+            &vars,
+        );
+
+        assert_eq!(
+            actual,
+            "# Cache builds\n2026-10-18 by Some Name on main\n{{unknown}}\n"
+        );
+    }
+
+    #[test]
+    fn test_template__template_path() {
+        let dir = Path::new("templates");
+
+        assert_eq!(
+            template_path(dir, "proposal").unwrap(),
+            dir.join("proposal.md")
+        );
+        assert!(template_path(dir, "../proposal").is_err());
+        assert!(template_path(dir, "").is_err());
+    }
+}
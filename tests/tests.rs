@@ -1,16 +1,24 @@
 #[cfg(test)]
+#[allow(clippy::io_other_error)]
 mod tests {
-    use eureka::config_manager::{ConfigManagement, ConfigType};
+    use eureka::config_manager::{ConfigManagement, ConfigOrigin, ConfigType, Profile, Profiles};
     use eureka::printer::{Print, PrintColor};
     use eureka::reader::ReadInput;
-    use eureka::{Eureka, EurekaOptions};
+    use eureka::{
+        AddOptions, ConfigCommand, Eureka, EurekaCommand, ListOptions, ProfileCommand,
+        SearchOptions, SortBy, ViewOptions,
+    };
 
-    use eureka::git::GitManagement;
+    use eureka::git::{CommitInfo, GitManagement};
+    use eureka::idea::IdeaStatus;
     use eureka::program_access::ProgramOpener;
     use git2::Oid;
     use std::cmp::Ordering as CmpOrdering;
+    use std::collections::BTreeMap;
     use std::io;
     use std::io::{Error, ErrorKind};
+    use std::ops::Range;
+    use std::path::{Path, PathBuf};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
@@ -38,6 +46,10 @@ mod tests {
                 Ok("some-path".to_string())
             }
 
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
             fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
                 unimplemented!()
             }
@@ -46,6 +58,46 @@ mod tests {
                 RM_COUNTER.fetch_add(1, Ordering::SeqCst);
                 Ok(())
             }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
         }
 
         let mut eureka = Eureka::new(
@@ -55,12 +107,9 @@ mod tests {
             DefaultGit {},
             DefaultMockProgramOpener {},
         );
-        let opts = EurekaOptions {
-            clear_config: true,
-            view: false,
-        };
+        let command = EurekaCommand::Config(ConfigCommand::Clear);
 
-        let actual = eureka.run(opts);
+        let actual = eureka.run(command);
 
         assert!(actual.is_ok());
 
@@ -69,9 +118,9 @@ mod tests {
     }
 
     #[test]
-    fn test_view_ideas() {
+    fn test_config_profiles_add() {
         struct MockConfigManager;
-        static READ_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static WRITE_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
         impl ConfigManagement for MockConfigManager {
             fn config_dir_create(&self) -> io::Result<()> {
@@ -82,14 +131,12 @@ mod tests {
                 unimplemented!()
             }
 
-            fn config_read(&self, file: ConfigType) -> io::Result<String> {
-                let counter = READ_COUNTER.fetch_add(1, Ordering::SeqCst);
-                if counter > 0 {
-                    panic!("Should only be read once");
-                }
+            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
+                unimplemented!()
+            }
 
-                assert_eq!(file, ConfigType::Repo);
-                Ok("specific-repo-path".to_string())
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
             }
 
             fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
@@ -97,71 +144,63 @@ mod tests {
             }
 
             fn config_rm(&self) -> io::Result<()> {
-                Ok(())
+                unimplemented!()
             }
-        }
-
-        struct MockProgramAccess;
 
-        impl ProgramOpener for MockProgramAccess {
-            fn open_editor(&self, _file_path: &str) -> io::Result<()> {
+            fn pending_push_mark(&self) -> io::Result<()> {
                 unimplemented!()
             }
 
-            fn open_pager(&self, file_path: &str) -> io::Result<()> {
-                assert_eq!(file_path, "specific-repo-path/README.md");
-                Ok(())
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
             }
-        }
-
-        let mut eureka = Eureka::new(
-            MockConfigManager,
-            DefaultMockPrinter {},
-            DefaultMockReader {},
-            DefaultGit {},
-            MockProgramAccess,
-        );
-        let opts = EurekaOptions {
-            clear_config: false,
-            view: true,
-        };
 
-        let actual = eureka.run(opts);
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
 
-        assert!(actual.is_ok());
-    }
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
 
-    #[test]
-    fn test_config_dir_is_missing() {
-        struct MockConfigManager;
-        static READ_COUNTER: AtomicUsize = AtomicUsize::new(0);
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                Ok(Profiles {
+                    default: String::from("default"),
+                    profiles: BTreeMap::from([(
+                        String::from("default"),
+                        Profile {
+                            repo: Some(PathBuf::from("/personal")),
+                            ..Profile::default()
+                        },
+                    )]),
+                })
+            }
 
-        impl ConfigManagement for MockConfigManager {
-            fn config_dir_create(&self) -> io::Result<()> {
+            fn profiles_write(&self, profiles: Profiles) -> io::Result<()> {
+                WRITE_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(profiles.default, "default");
+                assert_eq!(
+                    profiles.profiles.keys().collect::<Vec<_>>(),
+                    ["default", "work"]
+                );
+                assert_eq!(profiles.profiles["work"].repo, Some(PathBuf::from("/work")));
+                assert_eq!(profiles.profiles["work"].branch.as_deref(), Some("trunk"));
                 Ok(())
             }
 
-            fn config_dir_exists(&self) -> bool {
-                // Config dir is missing
-                false
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
             }
 
-            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
-                let counter = READ_COUNTER.fetch_add(1, Ordering::SeqCst);
-                if counter == 0 {
-                    // First it checks if any config can be found and
-                    // based on that it decides to create the config dir
-                    Err(Error::new(ErrorKind::Other, "some-error"))
-                } else {
-                    Ok(String::from("some-ok"))
-                }
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
             }
 
-            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+            fn config_text_read(&self) -> io::Result<String> {
                 unimplemented!()
             }
 
-            fn config_rm(&self) -> io::Result<()> {
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
                 unimplemented!()
             }
         }
@@ -174,15 +213,14 @@ mod tests {
             }
 
             fn println(&mut self, value: &str) -> io::Result<()> {
-                assert_eq!(value, "First time setup complete. Happy ideation!");
+                assert_eq!(value, "Added profile work, use it with --profile work");
                 Ok(())
             }
         }
 
         impl PrintColor for MockPrinter {
             fn fts_banner(&mut self) -> io::Result<()> {
-                // noop
-                Ok(())
+                unimplemented!()
             }
 
             fn input_header(&mut self, _value: &str) -> io::Result<()> {
@@ -192,6 +230,24 @@ mod tests {
             fn error(&mut self, _value: &str) -> io::Result<()> {
                 unimplemented!()
             }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
         }
 
         let mut eureka = Eureka::new(
@@ -201,135 +257,123 @@ mod tests {
             DefaultGit {},
             DefaultMockProgramOpener {},
         );
-        let opts = EurekaOptions {
-            clear_config: false,
-            view: false,
+        let profile = |repo: &str| Profile {
+            repo: Some(PathBuf::from(repo)),
+            branch: Some(String::from("trunk")),
+            ..Profile::default()
         };
 
-        let actual = eureka.run(opts);
-
+        let relative = eureka.run(EurekaCommand::Config(ConfigCommand::Profiles(
+            ProfileCommand::Add(String::from("work"), profile("work")),
+        )));
+        let existing = eureka.run(EurekaCommand::Config(ConfigCommand::Profiles(
+            ProfileCommand::Add(String::from("default"), profile("/work")),
+        )));
+        let remove_default = eureka.run(EurekaCommand::Config(ConfigCommand::Profiles(
+            ProfileCommand::Remove(String::from("default")),
+        )));
+        let actual = eureka.run(EurekaCommand::Config(ConfigCommand::Profiles(
+            ProfileCommand::Add(String::from("work"), profile("/work")),
+        )));
+
+        assert_eq!(relative.unwrap_err().to_string(), "Path must be absolute");
+        assert_eq!(
+            existing.unwrap_err().to_string(),
+            "There already is a profile called default"
+        );
+        assert_eq!(
+            remove_default.unwrap_err().to_string(),
+            "default is the default profile, make another profile the default first"
+        );
         assert!(actual.is_ok());
-        assert!(counter_equals(2, &READ_COUNTER));
+        assert!(counter_equals(1, &WRITE_COUNTER));
     }
 
     #[test]
-    fn test_setup_repo() {
-        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    fn test_config_show_origin() {
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
         struct MockConfigManager;
 
         impl ConfigManagement for MockConfigManager {
             fn config_dir_create(&self) -> io::Result<()> {
-                Ok(())
+                unimplemented!()
             }
 
             fn config_dir_exists(&self) -> bool {
-                true
+                unimplemented!()
             }
 
-            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
-                Err(Error::new(ErrorKind::Other, "some-error"))
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(String::from("/project")),
+                    ConfigType::File => Ok(String::from("IDEAS.md")),
+                    ConfigType::Remote => Ok(String::from("upstream")),
+                    ConfigType::Editor => Ok(String::from("vim")),
+                    ConfigType::Branch
+                    | ConfigType::Storage
+                    | ConfigType::Template
+                    | ConfigType::Position
+                    | ConfigType::Pager => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
             }
 
-            fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
+            fn config_origin(&self, file: ConfigType) -> io::Result<ConfigOrigin> {
                 match file {
-                    ConfigType::Repo => assert_eq!(value, "/absolute/path/to/specific-repo-path"),
+                    ConfigType::Repo | ConfigType::File => {
+                        Ok(ConfigOrigin::Local(PathBuf::from("/project/.eureka.toml")))
+                    }
+                    ConfigType::Remote => Ok(ConfigOrigin::Profile(String::from("work"))),
+                    ConfigType::Editor => Ok(ConfigOrigin::CommandLine),
+                    _ => unimplemented!(),
                 }
-                Ok(())
             }
 
-            fn config_rm(&self) -> io::Result<()> {
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
                 unimplemented!()
             }
-        }
 
-        struct MockPrinter;
-
-        impl Print for MockPrinter {
-            fn print(&mut self, _value: &str) -> io::Result<()> {
+            fn config_rm(&self) -> io::Result<()> {
                 unimplemented!()
             }
 
-            fn println(&mut self, value: &str) -> io::Result<()> {
-                assert_eq!(value, "First time setup complete. Happy ideation!");
-                Ok(())
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
             }
-        }
 
-        impl PrintColor for MockPrinter {
-            fn fts_banner(&mut self) -> io::Result<()> {
-                // noop
-                Ok(())
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
             }
 
-            fn input_header(&mut self, value: &str) -> io::Result<()> {
-                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
-                if counter == 0 {
-                    assert_eq!(value, "Absolute path to your idea repo");
-                } else {
-                    assert_eq!(value, "Name of branch (default: main)");
-                }
-
-                Ok(())
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
             }
 
-            fn error(&mut self, _value: &str) -> io::Result<()> {
+            fn template_read(&self, _name: &str) -> io::Result<String> {
                 unimplemented!()
             }
-        }
-
-        struct MockReader;
 
-        impl ReadInput for MockReader {
-            fn read_input(&mut self) -> io::Result<String> {
-                Ok(String::from("/absolute/path/to/specific-repo-path"))
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
             }
-        }
-
-        let mut eureka = Eureka::new(
-            MockConfigManager {},
-            MockPrinter {},
-            MockReader {},
-            DefaultGit {},
-            DefaultMockProgramOpener {},
-        );
-        let opts = EurekaOptions {
-            clear_config: false,
-            view: false,
-        };
-
-        let actual = eureka.run(opts);
-
-        assert!(actual.is_ok());
-    }
-
-    #[test]
-    fn test_setup_defaults_to_main_branch() {
-        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
-
-        struct MockConfigManager;
 
-        impl ConfigManagement for MockConfigManager {
-            fn config_dir_create(&self) -> io::Result<()> {
-                Ok(())
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
             }
 
-            fn config_dir_exists(&self) -> bool {
-                true
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
             }
 
-            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
-                Err(Error::new(ErrorKind::Other, "some-error"))
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
             }
 
-            fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
-                match file {
-                    ConfigType::Repo => assert_eq!(value, "/absolute/path/to/specific-repo-path"),
-                }
-                Ok(())
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
             }
 
-            fn config_rm(&self) -> io::Result<()> {
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
                 unimplemented!()
             }
         }
@@ -342,275 +386,378 @@ mod tests {
             }
 
             fn println(&mut self, value: &str) -> io::Result<()> {
-                assert_eq!(value, "First time setup complete. Happy ideation!");
+                let counter = PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                let expected = [
+                    "repo      /project  (/project/.eureka.toml)",
+                    "file      IDEAS.md  (/project/.eureka.toml)",
+                    "branch    main  (default)",
+                    "remote    upstream  (profile work)",
+                    "storage   single-file  (default)",
+                    "template  not set",
+                    "position  bottom  (default)",
+                    "editor    vim  (command line)",
+                    "pager     not set",
+                ];
+                assert_eq!(value, expected[counter]);
                 Ok(())
             }
         }
 
         impl PrintColor for MockPrinter {
             fn fts_banner(&mut self) -> io::Result<()> {
-                // noop
-                Ok(())
+                unimplemented!()
             }
 
-            fn input_header(&mut self, value: &str) -> io::Result<()> {
-                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
-                if counter == 0 {
-                    assert_eq!(value, "Absolute path to your idea repo");
-                } else {
-                    assert_eq!(value, "Name of branch (default: main)");
-                }
-
-                Ok(())
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
             }
 
             fn error(&mut self, _value: &str) -> io::Result<()> {
                 unimplemented!()
             }
-        }
 
-        struct MockReader;
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
 
-        impl ReadInput for MockReader {
-            fn read_input(&mut self) -> io::Result<String> {
-                Ok(String::from("/absolute/path/to/specific-repo-path"))
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
             }
         }
 
         let mut eureka = Eureka::new(
             MockConfigManager {},
             MockPrinter {},
-            MockReader {},
+            DefaultMockReader {},
             DefaultGit {},
             DefaultMockProgramOpener {},
         );
-        let opts = EurekaOptions {
-            clear_config: false,
-            view: false,
-        };
+        let command = EurekaCommand::Config(ConfigCommand::Show { origin: true });
 
-        let actual = eureka.run(opts);
+        let actual = eureka.run(command);
 
         assert!(actual.is_ok());
+        assert!(counter_equals(9, &PRINT_COUNTER));
     }
 
     #[test]
-    fn test_setup_repo_path_asks_until_user_provides_value() {
-        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
-        static READ_INPUT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    fn test_config_set() {
+        static WRITE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
         struct MockConfigManager;
 
         impl ConfigManagement for MockConfigManager {
             fn config_dir_create(&self) -> io::Result<()> {
-                Ok(())
+                unimplemented!()
             }
 
             fn config_dir_exists(&self) -> bool {
-                true
+                unimplemented!()
             }
 
             fn config_read(&self, _file: ConfigType) -> io::Result<String> {
-                Err(Error::new(ErrorKind::Other, "some-error"))
+                unimplemented!()
+            }
+
+            fn config_origin(&self, file: ConfigType) -> io::Result<ConfigOrigin> {
+                assert_eq!(file, ConfigType::Branch);
+                Ok(ConfigOrigin::Local(PathBuf::from("/project/.eureka.toml")))
             }
 
             fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
-                match file {
-                    ConfigType::Repo => assert_eq!(value, "/absolute/path/to/specific-repo-path"),
-                }
+                WRITE_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(file, ConfigType::Branch);
+                assert_eq!(value, "trunk");
                 Ok(())
             }
 
             fn config_rm(&self) -> io::Result<()> {
                 unimplemented!()
             }
-        }
 
-        struct MockPrinter;
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
 
-        impl Print for MockPrinter {
-            fn print(&mut self, _value: &str) -> io::Result<()> {
+            fn pending_push_clear(&self) -> io::Result<()> {
                 unimplemented!()
             }
 
-            fn println(&mut self, value: &str) -> io::Result<()> {
-                assert_eq!(value, "First time setup complete. Happy ideation!");
-                Ok(())
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
             }
-        }
 
-        impl PrintColor for MockPrinter {
-            fn fts_banner(&mut self) -> io::Result<()> {
-                // noop
-                Ok(())
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
             }
 
-            fn input_header(&mut self, value: &str) -> io::Result<()> {
-                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
-                if counter <= 10 {
-                    assert_eq!(value, "Absolute path to your idea repo");
-                } else {
-                    assert_eq!(value, "Name of branch (default: main)");
-                }
-                Ok(())
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
             }
 
-            fn error(&mut self, value: &str) -> io::Result<()> {
-                assert_eq!(value, "Path must be absolute");
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(
+                    value,
+                    "Note that branch is set by /project/.eureka.toml, which wins over the config"
+                );
                 Ok(())
             }
         }
 
-        struct MockReader;
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
 
-        impl ReadInput for MockReader {
-            fn read_input(&mut self) -> io::Result<String> {
-                let counter = READ_INPUT_COUNTER.fetch_add(1, Ordering::SeqCst);
-                if counter < 5 {
-                    // Return empty string to prompt it to ask again
-                    Ok(String::new())
-                } else if counter < 10 {
-                    // Return relative path to prompt it to ask again
-                    Ok(String::from("some-relative-path"))
-                } else {
-                    Ok(String::from("/absolute/path/to/specific-repo-path"))
-                }
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
             }
         }
 
         let mut eureka = Eureka::new(
             MockConfigManager {},
             MockPrinter {},
-            MockReader {},
+            DefaultMockReader {},
             DefaultGit {},
             DefaultMockProgramOpener {},
         );
-        let opts = EurekaOptions {
-            clear_config: false,
-            view: false,
-        };
-
-        let actual = eureka.run(opts);
 
-        assert!(actual.is_ok());
+        let invalid = eureka
+            .run(EurekaCommand::Config(ConfigCommand::Set(
+                ConfigType::Branch,
+                String::from("bad..name"),
+            )))
+            .map_err(|e| e.kind());
+        let valid = eureka.run(EurekaCommand::Config(ConfigCommand::Set(
+            ConfigType::Branch,
+            String::from("trunk"),
+        )));
+
+        assert_eq!(invalid, Err(ErrorKind::InvalidInput));
+        assert!(valid.is_ok());
+        assert!(counter_equals(1, &WRITE_COUNTER));
+        assert!(counter_equals(1, &PRINT_COUNTER));
     }
 
     #[test]
-    fn test_idea_summary_asks_until_user_provides_value() {
-        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
-        static READ_INPUT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    fn test_config_edit() {
+        static EDIT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static WRITE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static ERROR_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
         struct MockConfigManager;
 
         impl ConfigManagement for MockConfigManager {
             fn config_dir_create(&self) -> io::Result<()> {
-                Ok(())
+                unimplemented!()
             }
 
             fn config_dir_exists(&self) -> bool {
-                true
+                unimplemented!()
             }
 
             fn config_read(&self, _file: ConfigType) -> io::Result<String> {
-                Ok(String::from("specific-config-string"))
+                unimplemented!()
             }
 
-            fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
-                match file {
-                    ConfigType::Repo => assert_eq!(value, "specific-repo-path"),
-                }
-                Ok(())
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
             }
 
             fn config_rm(&self) -> io::Result<()> {
                 unimplemented!()
             }
-        }
 
-        struct MockPrinter;
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
 
-        impl Print for MockPrinter {
-            fn print(&mut self, value: &str) -> io::Result<()> {
-                assert_eq!(value, "First time setup complete. Happy ideation!");
-                Ok(())
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
             }
 
-            fn println(&mut self, _value: &str) -> io::Result<()> {
-                // noop
-                Ok(())
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
             }
-        }
 
-        impl PrintColor for MockPrinter {
-            fn fts_banner(&mut self) -> io::Result<()> {
-                // noop
-                Ok(())
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
             }
 
-            fn input_header(&mut self, value: &str) -> io::Result<()> {
-                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
-                if counter <= 5 {
-                    assert_eq!(value, ">> Idea summary");
-                } else {
-                    assert_eq!(value, "Name of branch (default: main)");
-                }
-                Ok(())
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
             }
 
-            fn error(&mut self, _value: &str) -> io::Result<()> {
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
                 unimplemented!()
             }
-        }
 
-        struct MockReader;
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
 
-        impl ReadInput for MockReader {
-            fn read_input(&mut self) -> io::Result<String> {
-                let counter = READ_INPUT_COUNTER.fetch_add(1, Ordering::SeqCst);
-                match counter.cmp(&5) {
-                    CmpOrdering::Less => {
-                        // Return empty string to prompt it to ask again
-                        Ok(String::new())
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                Ok(String::from("{\"repo\":\"/ideas\"}"))
+            }
+
+            fn config_text_write(&self, text: &str) -> io::Result<()> {
+                let counter = WRITE_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => {
+                        assert_eq!(text, "{\"repo\":\"ideas\"}");
+                        Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            "The repo must be an absolute path: ideas",
+                        ))
+                    }
+                    _ => {
+                        assert_eq!(text, "{\"repo\":\"/ideas\",\"branch\":\"trunk\"}");
+                        Ok(())
                     }
-                    CmpOrdering::Equal => Ok(String::from("specific-idea-summary")),
-                    CmpOrdering::Greater => unimplemented!(),
                 }
             }
         }
 
-        struct MockGit;
+        struct MockPrinter;
 
-        impl GitManagement for MockGit {
-            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
-                Ok(())
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
             }
 
-            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "Edit the config again? y/n (default: y)");
                 Ok(())
             }
 
-            fn add(&self) -> Result<(), git2::Error> {
+            fn error(&mut self, value: &str) -> io::Result<()> {
+                ERROR_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(value, "The repo must be an absolute path: ideas");
                 Ok(())
             }
 
-            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
-                Ok(Oid::zero())
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
             }
 
-            fn push(&self, _branch_name: &str) -> Result<(), git2::Error> {
-                Ok(())
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
             }
         }
 
-        struct MockProgramAccess;
+        struct MockReader;
 
-        impl ProgramOpener for MockProgramAccess {
-            fn open_editor(&self, _file_path: &str) -> io::Result<()> {
-                Ok(())
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                Ok(String::from("y"))
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, file_path: &str) -> io::Result<()> {
+                // A mistake first, then the fixed config
+                let counter = EDIT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                let text = match counter {
+                    0 => "{\"repo\":\"ideas\"}",
+                    _ => "{\"repo\":\"/ideas\",\"branch\":\"trunk\"}",
+                };
+                std::fs::write(file_path, text)
             }
 
             fn open_pager(&self, _file_path: &str) -> io::Result<()> {
-                Ok(())
+                unimplemented!()
             }
         }
 
@@ -618,22 +765,23 @@ mod tests {
             MockConfigManager {},
             MockPrinter {},
             MockReader {},
-            MockGit {},
-            MockProgramAccess {},
+            DefaultGit {},
+            MockProgramOpener {},
         );
-        let opts = EurekaOptions {
-            clear_config: false,
-            view: false,
-        };
 
-        let actual = eureka.run(opts);
+        let actual = eureka.run(EurekaCommand::Config(ConfigCommand::Edit));
 
         assert!(actual.is_ok());
+        assert!(counter_equals(2, &EDIT_COUNTER));
+        assert!(counter_equals(2, &WRITE_COUNTER));
+        assert!(counter_equals(1, &ERROR_COUNTER));
     }
 
     #[test]
-    fn test_e2e_happy_path() {
-        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    fn test_config_edit_keeps_edits_when_given_up() {
+        static EDIT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static WRITE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static ERROR_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
         struct MockConfigManager;
 
@@ -643,13 +791,15 @@ mod tests {
             }
 
             fn config_dir_exists(&self) -> bool {
-                true
+                unimplemented!()
             }
 
-            fn config_read(&self, file: ConfigType) -> io::Result<String> {
-                match file {
-                    ConfigType::Repo => Ok("specific-repo".to_string()),
-                }
+            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
             }
 
             fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
@@ -659,6 +809,51 @@ mod tests {
             fn config_rm(&self) -> io::Result<()> {
                 unimplemented!()
             }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                Ok(String::from("{\"repo\":\"/ideas\"}"))
+            }
+
+            fn config_text_write(&self, text: &str) -> io::Result<()> {
+                WRITE_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(text, "{\"repo\":\"ideas\"}");
+                Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The repo must be an absolute path: ideas",
+                ))
+            }
         }
 
         struct MockPrinter;
@@ -668,17 +863,8 @@ mod tests {
                 unimplemented!()
             }
 
-            fn println(&mut self, value: &str) -> io::Result<()> {
-                let counter = PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
-                match counter {
-                    0 => assert_eq!(value, "Adding and committing your new idea to main.."),
-                    1 => assert_eq!(value, "Added and committed!"),
-                    2 => assert_eq!(value, "Pushing your new idea.."),
-                    3 => assert_eq!(value, "Pushed!"),
-                    _ => panic!("Unknown state"),
-                }
-
-                Ok(())
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
             }
         }
 
@@ -688,11 +874,31 @@ mod tests {
             }
 
             fn input_header(&mut self, value: &str) -> io::Result<()> {
-                assert_eq!(value, ">> Idea summary");
+                assert_eq!(value, "Edit the config again? y/n (default: y)");
                 Ok(())
             }
 
-            fn error(&mut self, _value: &str) -> io::Result<()> {
+            fn error(&mut self, value: &str) -> io::Result<()> {
+                ERROR_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(value, "The repo must be an absolute path: ideas");
+                Ok(())
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
                 unimplemented!()
             }
         }
@@ -701,66 +907,5752 @@ mod tests {
 
         impl ReadInput for MockReader {
             fn read_input(&mut self) -> io::Result<String> {
-                Ok(String::from("read-input-string"))
+                Ok(String::from("n"))
             }
         }
 
-        struct MockGit;
+        struct MockProgramOpener;
 
-        impl GitManagement for MockGit {
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, file_path: &str) -> io::Result<()> {
+                EDIT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                std::fs::write(file_path, "{\"repo\":\"ideas\"}")
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            MockPrinter {},
+            MockReader {},
+            DefaultGit {},
+            MockProgramOpener {},
+        );
+
+        let actual = eureka
+            .run(EurekaCommand::Config(ConfigCommand::Edit))
+            .unwrap_err()
+            .to_string();
+
+        let (_, kept_path) = actual.split_once("Your edits are kept in ").unwrap();
+        let kept = std::fs::read_to_string(kept_path).unwrap();
+        std::fs::remove_file(kept_path).unwrap();
+        assert!(actual.starts_with("The config was left as it was"));
+        assert_eq!(kept, "{\"repo\":\"ideas\"}");
+        assert!(counter_equals(1, &EDIT_COUNTER));
+        assert!(counter_equals(1, &WRITE_COUNTER));
+        assert!(counter_equals(1, &ERROR_COUNTER));
+    }
+
+    #[test]
+    fn test_view_ideas() {
+        struct MockConfigManager;
+        static READ_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                READ_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match file {
+                    ConfigType::Repo => Ok("specific-repo-path".to_string()),
+                    // The idea file isn't configured, README.md is used
+                    ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                    _ => panic!("Only the repo and idea file are read"),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockProgramAccess;
+
+        impl ProgramOpener for MockProgramAccess {
+            fn open_editor(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn open_pager(&self, file_path: &str) -> io::Result<()> {
+                assert_eq!(file_path, "specific-repo-path/README.md");
+                Ok(())
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager,
+            DefaultMockPrinter {},
+            DefaultMockReader {},
+            DefaultGit {},
+            MockProgramAccess,
+        );
+        let command = EurekaCommand::View(ViewOptions {
+            raw: true,
+            ..ViewOptions::default()
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(2, &READ_COUNTER));
+    }
+
+    #[test]
+    fn test_config_dir_is_missing() {
+        struct MockConfigManager;
+        static READ_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                // Config dir is missing
+                false
+            }
+
+            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
+                let counter = READ_COUNTER.fetch_add(1, Ordering::SeqCst);
+                if counter == 0 {
+                    // First it checks if any config can be found and
+                    // based on that it decides to create the config dir
+                    Err(Error::new(ErrorKind::Other, "some-error"))
+                } else {
+                    Ok(String::from("some-ok"))
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "First time setup complete. Happy ideation!");
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            MockPrinter {},
+            DefaultMockReader {},
+            DefaultGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(7, &READ_COUNTER));
+    }
+
+    #[test]
+    fn test_setup_repo() {
+        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static READ_INPUT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct MockConfigManager;
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
+                Err(Error::new(ErrorKind::Other, "some-error"))
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
+                match file {
+                    ConfigType::Repo => assert_eq!(value, "/absolute/path/to/specific-repo-path"),
+                    ConfigType::Branch => assert_eq!(value, "specific-branch"),
+                    ConfigType::Remote => assert_eq!(value, "specific-remote"),
+                    ConfigType::Storage => assert_eq!(value, "file-per-idea"),
+                    ConfigType::Editor => assert_eq!(value, "echo --wait"),
+                    ConfigType::Position
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => {
+                        unimplemented!()
+                    }
+                }
+                Ok(())
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "First time setup complete. Happy ideation!");
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => assert_eq!(value, "Absolute path to your idea repo"),
+                    1 => assert_eq!(value, "Name of branch (default: main)"),
+                    2 => assert_eq!(value, "Name of remote (default: origin)"),
+                    3 => assert_eq!(
+                        value,
+                        "Storage layout, single-file or file-per-idea (default: single-file)"
+                    ),
+                    4 | 5 => assert_eq!(value, "Editor, leave empty to use $VISUAL or $EDITOR"),
+                    6 => assert_eq!(value, "Pager, leave empty to use $PAGER"),
+                    _ => panic!("Unknown state"),
+                }
+
+                Ok(())
+            }
+
+            fn error(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(
+                    value,
+                    "Could not find some-non-existing-editor on your $PATH"
+                );
+                Ok(())
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                let counter = READ_INPUT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => Ok(String::from("/absolute/path/to/specific-repo-path")),
+                    1 => Ok(String::from("specific-branch")),
+                    2 => Ok(String::from("specific-remote")),
+                    3 => Ok(String::from("file-per-idea")),
+                    // Not installed, so it's asked for again
+                    4 => Ok(String::from("some-non-existing-editor")),
+                    5 => Ok(String::from("echo --wait")),
+                    // Empty to use $PAGER
+                    _ => Ok(String::new()),
+                }
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            MockPrinter {},
+            MockReader {},
+            MissingRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn test_setup_defaults_to_main_branch() {
+        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static READ_INPUT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct MockConfigManager;
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
+                Err(Error::new(ErrorKind::Other, "some-error"))
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
+                match file {
+                    ConfigType::Repo => assert_eq!(value, "/absolute/path/to/specific-repo-path"),
+                    ConfigType::Branch => assert_eq!(value, "main"),
+                    ConfigType::Remote => assert_eq!(value, "origin"),
+                    ConfigType::Storage => assert_eq!(value, "single-file"),
+                    ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => {
+                        unimplemented!()
+                    }
+                }
+                Ok(())
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "First time setup complete. Happy ideation!");
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => assert_eq!(value, "Absolute path to your idea repo"),
+                    1 => assert_eq!(value, "Name of branch (default: main)"),
+                    2 => assert_eq!(value, "Name of remote (default: origin)"),
+                    3 => assert_eq!(
+                        value,
+                        "Storage layout, single-file or file-per-idea (default: single-file)"
+                    ),
+                    4 => assert_eq!(value, "Editor, leave empty to use $VISUAL or $EDITOR"),
+                    5 => assert_eq!(value, "Pager, leave empty to use $PAGER"),
+                    _ => panic!("Unknown state"),
+                }
+
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                let counter = READ_INPUT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                if counter == 0 {
+                    Ok(String::from("/absolute/path/to/specific-repo-path"))
+                } else {
+                    // Return empty string to use the default value
+                    Ok(String::new())
+                }
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            MockPrinter {},
+            MockReader {},
+            MissingRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn test_setup_detects_branch_and_remote_from_repo() {
+        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static READ_INPUT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct MockConfigManager;
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
+                Err(Error::other("some-error"))
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
+                match file {
+                    ConfigType::Repo => assert_eq!(value, "/absolute/path/to/specific-repo-path"),
+                    ConfigType::Branch => assert_eq!(value, "trunk"),
+                    ConfigType::Remote => assert_eq!(value, "upstream"),
+                    ConfigType::Storage => assert_eq!(value, "single-file"),
+                    ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => {
+                        unimplemented!()
+                    }
+                }
+                Ok(())
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "First time setup complete. Happy ideation!");
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => assert_eq!(value, "Absolute path to your idea repo"),
+                    1 => assert_eq!(value, "Name of branch (default: trunk)"),
+                    2 => assert_eq!(value, "Name of remote (default: upstream)"),
+                    3 => assert_eq!(
+                        value,
+                        "Storage layout, single-file or file-per-idea (default: single-file)"
+                    ),
+                    4 => assert_eq!(value, "Editor, leave empty to use $VISUAL or $EDITOR"),
+                    5 => assert_eq!(value, "Pager, leave empty to use $PAGER"),
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                let counter = READ_INPUT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                if counter == 0 {
+                    Ok(String::from("/absolute/path/to/specific-repo-path"))
+                } else {
+                    // Return empty string to use the default value
+                    Ok(String::new())
+                }
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, repo_path: &str) -> Result<(), git2::Error> {
+                assert_eq!(repo_path, "/absolute/path/to/specific-repo-path");
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                Ok(Some(String::from("trunk")))
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                Ok(vec![String::from("upstream"), String::from("mirror")])
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            MockPrinter {},
+            MockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(6, &INPUT_HEADER_COUNTER));
+    }
+
+    #[test]
+    fn test_setup_repo_path_asks_until_user_provides_value() {
+        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static READ_INPUT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct MockConfigManager;
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
+                Err(Error::new(ErrorKind::Other, "some-error"))
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
+                match file {
+                    ConfigType::Repo => assert_eq!(value, "/absolute/path/to/specific-repo-path"),
+                    ConfigType::Branch => assert_eq!(value, "main"),
+                    ConfigType::Remote => assert_eq!(value, "origin"),
+                    ConfigType::Storage => assert_eq!(value, "single-file"),
+                    ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => {
+                        unimplemented!()
+                    }
+                }
+                Ok(())
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "First time setup complete. Happy ideation!");
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0..=10 => assert_eq!(value, "Absolute path to your idea repo"),
+                    11 => assert_eq!(value, "Name of branch (default: main)"),
+                    12 => assert_eq!(value, "Name of remote (default: origin)"),
+                    13 => assert_eq!(
+                        value,
+                        "Storage layout, single-file or file-per-idea (default: single-file)"
+                    ),
+                    14 => assert_eq!(value, "Editor, leave empty to use $VISUAL or $EDITOR"),
+                    15 => assert_eq!(value, "Pager, leave empty to use $PAGER"),
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+
+            fn error(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "Path must be absolute");
+                Ok(())
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                let counter = READ_INPUT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                if counter < 5 {
+                    // Return empty string to prompt it to ask again
+                    Ok(String::new())
+                } else if counter < 10 {
+                    // Return relative path to prompt it to ask again
+                    Ok(String::from("some-relative-path"))
+                } else if counter == 10 {
+                    Ok(String::from("/absolute/path/to/specific-repo-path"))
+                } else {
+                    // Return empty string to use the default value
+                    Ok(String::new())
+                }
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            MockPrinter {},
+            MockReader {},
+            MissingRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn test_idea_summary_asks_until_user_provides_value() {
+        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static READ_INPUT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Storage => Ok(String::from("single-file")),
+                    ConfigType::Position => Ok(String::from("bottom")),
+                    ConfigType::Template | ConfigType::File => {
+                        Err(Error::new(ErrorKind::NotFound, "some-error"))
+                    }
+                    _ => Ok(String::from("specific-config-string")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, file: ConfigType, value: String) -> io::Result<()> {
+                match file {
+                    ConfigType::Repo => assert_eq!(value, "specific-repo-path"),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => {
+                        unimplemented!()
+                    }
+                }
+                Ok(())
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                false
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "First time setup complete. Happy ideation!");
+                Ok(())
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
+                if counter <= 5 {
+                    assert_eq!(value, ">> Idea summary");
+                } else {
+                    assert_eq!(value, "Name of branch (default: main)");
+                }
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                let counter = READ_INPUT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter.cmp(&5) {
+                    CmpOrdering::Less => {
+                        // Return empty string to prompt it to ask again
+                        Ok(String::new())
+                    }
+                    CmpOrdering::Equal => Ok(String::from("specific-idea-summary")),
+                    CmpOrdering::Greater => unimplemented!(),
+                }
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        struct MockProgramAccess;
+
+        impl ProgramOpener for MockProgramAccess {
+            fn open_editor(&self, _file_path: &str) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            MockReader {},
+            MockGit {},
+            MockProgramAccess {},
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn test_e2e_happy_path() {
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let repo_path = repo_dir.path().display().to_string();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                false
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                let counter = PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => assert_eq!(value, "Pulling latest ideas from origin/main.."),
+                    1 => assert_eq!(value, "Adding and committing your new idea to main.."),
+                    2 => assert_eq!(value, "Added and committed!"),
+                    3 => assert_eq!(value, "Pushing your new idea.."),
+                    4 => assert_eq!(value, "Pushed!"),
+                    _ => panic!("Unknown state"),
+                }
+
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, ">> Idea summary");
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                Ok(String::from("read-input-string"))
+            }
+        }
+
+        struct MockGit(String);
+
+        impl GitManagement for MockGit {
             fn init(&mut self, repo_path: &str) -> Result<(), git2::Error> {
-                assert_eq!(repo_path, "specific-repo");
+                assert_eq!(repo_path, self.0);
+                Ok(())
+            }
+
+            fn checkout_branch(&self, branch_name: &str) -> Result<(), git2::Error> {
+                assert_eq!(branch_name, "main");
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                assert_eq!(subject, "read-input-string");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, remote_name: &str, branch_name: &str) -> Result<(), git2::Error> {
+                assert_eq!(remote_name, "origin");
+                assert_eq!(branch_name, "main");
+                Ok(())
+            }
+
+            fn pull(&self, remote_name: &str, branch_name: &str) -> Result<(), git2::Error> {
+                assert_eq!(remote_name, "origin");
+                assert_eq!(branch_name, "main");
+                Ok(())
+            }
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        struct MockProgramOpener(String);
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, file_path: &str) -> io::Result<()> {
+                assert_eq!(file_path, format!("{}/README.md", self.0));
+                std::fs::write(file_path, "specific-idea")
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_path.clone()),
+            MockPrinter {},
+            MockReader {},
+            MockGit(repo_path.clone()),
+            MockProgramOpener(repo_path),
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn test_capture_idea_without_editor() {
+        static COMMIT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let readme_path = repo_dir.path().join("README.md");
+        std::fs::write(&readme_path, "# Ideas\n").unwrap();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                false
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                COMMIT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(subject, "specific-summary");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions {
+            message: Some(String::from("specific-summary")),
+            body: Some(String::from("specific-body\n")),
+            offline: false,
+            template: None,
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &COMMIT_COUNTER));
+
+        let contents = std::fs::read_to_string(&readme_path).unwrap();
+        assert!(contents.starts_with("# Ideas\n\n## specific-summary\n<!-- eureka: created="));
+        assert!(contents.ends_with("; author=Some Name -->\n\nspecific-body\n"));
+    }
+
+    #[test]
+    fn test_capture_idea_file_per_idea() {
+        static ADD_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Storage => Ok(String::from("file-per-idea")),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                false
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error> {
+                ADD_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(paths.len(), 2);
+                assert!(paths[0].starts_with("ideas"));
+                assert!(paths[0].to_string_lossy().ends_with("-specific-summary.md"));
+                assert_eq!(paths[1], PathBuf::from("README.md"));
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                assert_eq!(subject, "specific-summary");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions {
+            message: Some(String::from("specific-summary")),
+            body: Some(String::from("specific-body\n")),
+            offline: false,
+            template: None,
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &ADD_COUNTER));
+
+        let idea_files = std::fs::read_dir(repo_dir.path().join("ideas"))
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(idea_files.len(), 1);
+        let idea = std::fs::read_to_string(idea_files[0].as_ref().unwrap().path()).unwrap();
+        assert!(idea.starts_with("---\ntitle: \"specific-summary\"\ncreated: "));
+        assert!(
+            idea.ends_with("tags: []\nstatus: new\nauthor: \"Some Name\"\n---\n\nspecific-body\n")
+        );
+
+        let readme = std::fs::read_to_string(repo_dir.path().join("README.md")).unwrap();
+        assert!(readme.contains("- [specific-summary](ideas/"));
+    }
+
+    #[test]
+    fn test_ideas_include_commits() {
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            repo_dir.path().join("README.md"),
+            "# Ideas\n\n## First idea\n\nFirst body\n\n## Second idea\n",
+        )
+        .unwrap();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                path: &Path,
+                lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                assert_eq!(path, Path::new("README.md"));
+                assert_eq!(lines, &[3, 7]);
+                Ok(vec![
+                    Some(CommitInfo {
+                        id: Oid::zero(),
+                        time: chrono::DateTime::parse_from_rfc3339("2026-10-18T09:30:00+02:00")
+                            .unwrap(),
+                        summary: String::from("First idea"),
+                        message: String::from("First idea"),
+                    }),
+                    None,
+                ])
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_dir.path().display().to_string()),
+            DefaultMockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+
+        let ideas = eureka.ideas().unwrap();
+
+        assert_eq!(ideas.len(), 2);
+        assert_eq!(ideas[0].id, "f852e9f");
+        assert_eq!(ideas[0].title, "First idea");
+        assert_eq!(ideas[0].body, "First body");
+        assert_eq!(ideas[0].commit.as_ref().unwrap().id, Oid::zero());
+        assert_eq!(
+            ideas[0].date().unwrap().to_rfc3339(),
+            "2026-10-18T09:30:00+02:00"
+        );
+        assert_eq!(ideas[1].id, "4d66629");
+        assert_eq!(ideas[1].title, "Second idea");
+        assert!(ideas[1].commit.is_none());
+    }
+
+    #[test]
+    fn test_capture_idea_without_editor_when_config_missing() {
+        struct MockConfigManager;
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn config_read(&self, _file: ConfigType) -> io::Result<String> {
+                Err(Error::new(ErrorKind::NotFound, "some-error"))
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            DefaultMockPrinter {},
+            DefaultMockReader {},
+            DefaultGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions {
+            message: None,
+            body: Some(String::from("specific-summary\nspecific-body")),
+            offline: false,
+            template: None,
+        });
+
+        let actual = eureka.run(command).map_err(|e| e.kind());
+
+        assert_eq!(actual, Err(ErrorKind::NotFound));
+    }
+
+    #[test]
+    fn test_doctor() {
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), "# Ideas\n").unwrap();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                false
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert!(value.starts_with("[ok] "));
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, value: &str) -> io::Result<()> {
+                panic!("Unexpected error: {}", value)
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                Ok(vec![String::from("origin")])
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+
+        let actual = eureka.run(EurekaCommand::Doctor);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(4, &PRINT_COUNTER));
+    }
+
+    #[test]
+    fn test_pull_conflict_aborts_before_editor() {
+        struct MockConfigManager;
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok("specific-repo".to_string()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, "Pulling latest ideas from origin/main..");
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, ">> Idea summary");
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                Ok(String::from("read-input-string"))
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Err(git2::Error::new(
+                    git2::ErrorCode::Conflict,
+                    git2::ErrorClass::Merge,
+                    "Conflicting changes in README.md",
+                ))
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            MockPrinter {},
+            MockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command).unwrap_err().to_string();
+
+        assert!(actual.contains("Conflicting changes in README.md"));
+        assert!(actual.contains("Resolve this manually"));
+    }
+
+    #[test]
+    fn test_failed_push_keeps_idea_committed_locally() {
+        static MARK_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static HINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                MARK_COUNTER.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                false
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                if value == "Committed your new idea locally, run `eureka sync` to push it later" {
+                    HINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                }
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                Ok(String::from("read-input-string"))
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Err(git2::Error::from_str("some-network-error"))
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, file_path: &str) -> io::Result<()> {
+                std::fs::write(file_path, "specific-idea")
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            MockReader {},
+            MockGit {},
+            MockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions::default());
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &MARK_COUNTER));
+        assert!(counter_equals(1, &HINT_COUNTER));
+    }
+
+    #[test]
+    fn test_offline_does_not_pull_or_push() {
+        static MARK_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static HINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                MARK_COUNTER.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                if value == "Run `eureka sync` to push your new idea once you're back online" {
+                    HINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                }
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                Ok(String::from("read-input-string"))
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, file_path: &str) -> io::Result<()> {
+                std::fs::write(file_path, "specific-idea")
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            MockReader {},
+            MockGit {},
+            MockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions {
+            offline: true,
+            ..AddOptions::default()
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &MARK_COUNTER));
+        assert!(counter_equals(1, &HINT_COUNTER));
+    }
+
+    #[test]
+    fn test_sync_pushes_pending_ideas() {
+        static PUSH_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static CLEAR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct MockConfigManager;
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok("specific-repo".to_string()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                CLEAR_COUNTER.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                true
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                PUSH_COUNTER.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager {},
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+
+        let actual = eureka.run(EurekaCommand::Sync);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &PUSH_COUNTER));
+        assert!(counter_equals(1, &CLEAR_COUNTER));
+    }
+
+    #[test]
+    fn test_unchanged_idea_file_is_not_committed() {
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), "# Ideas\n").unwrap();
+
+        struct MockConfigManager(String);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Storage
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(
+                    value,
+                    "No changes were made to your idea file, nothing to commit"
+                );
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                Ok(String::from("read-input-string"))
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, _file_path: &str) -> io::Result<()> {
+                // Quit without saving
+                Ok(())
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            MockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            MockReader {},
+            MockGit {},
+            MockProgramOpener {},
+        );
+        let command = EurekaCommand::Add(AddOptions {
+            offline: true,
+            ..AddOptions::default()
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &PRINT_COUNTER));
+    }
+
+    #[test]
+    fn test_unchanged_idea_file_is_not_committed_in_any_layout() {
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        struct MockConfigManager(String, &'static str);
+
+        impl ConfigManagement for MockConfigManager {
+            fn config_dir_create(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_dir_exists(&self) -> bool {
+                true
+            }
+
+            fn config_read(&self, file: ConfigType) -> io::Result<String> {
+                match file {
+                    ConfigType::Repo => Ok(self.0.clone()),
+                    ConfigType::Storage => Ok(String::from(self.1)),
+                    ConfigType::Branch
+                    | ConfigType::Remote
+                    | ConfigType::Position
+                    | ConfigType::Editor
+                    | ConfigType::Pager
+                    | ConfigType::Template
+                    | ConfigType::File => Err(Error::new(ErrorKind::NotFound, "some-error")),
+                }
+            }
+
+            fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+                unimplemented!()
+            }
+
+            fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_rm(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_mark(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_clear(&self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn pending_push_exists(&self) -> bool {
+                unimplemented!()
+            }
+
+            fn template_read(&self, _name: &str) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn profiles_read(&self) -> io::Result<Profiles> {
+                unimplemented!()
+            }
+
+            fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+                unimplemented!()
+            }
+
+            fn config_text_read(&self) -> io::Result<String> {
+                unimplemented!()
+            }
+
+            fn config_text_write(&self, _text: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(
+                    value,
+                    "No changes were made to your idea file, nothing to commit"
+                );
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader(&'static str);
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                Ok(String::from(self.0))
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, _file_path: &str) -> io::Result<()> {
+                // Quit without saving
+                Ok(())
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        // Only what eureka wrote itself is left, whatever the layout
+        let cases = [
+            ("single-file", "read-input-string #tagged"),
+            ("file-per-idea", "read-input-string"),
+            ("file-per-idea", "read-input-string #tagged"),
+        ];
+        for (storage, summary) in cases {
+            let repo_dir = tempfile::TempDir::new().unwrap();
+            std::fs::write(repo_dir.path().join("README.md"), "# Ideas\n").unwrap();
+
+            let mut eureka = Eureka::new(
+                MockConfigManager(repo_dir.path().display().to_string(), storage),
+                MockPrinter {},
+                MockReader(summary),
+                MockGit {},
+                MockProgramOpener {},
+            );
+            let command = EurekaCommand::Add(AddOptions {
+                offline: true,
+                ..AddOptions::default()
+            });
+
+            let actual = eureka.run(command);
+
+            assert!(actual.is_ok());
+            let readme = std::fs::read_to_string(repo_dir.path().join("README.md")).unwrap();
+            assert_eq!(readme, "# Ideas\n");
+            let idea_files = std::fs::read_dir(repo_dir.path().join("ideas"))
+                .map(|files| files.count())
+                .unwrap_or_default();
+            assert_eq!(idea_files, 0);
+        }
+        assert!(counter_equals(3, &PRINT_COUNTER));
+    }
+
+    const LIST_README: &str = "# Ideas\n\n\
+                               ## Beta idea\n<!-- eureka: created=2026-10-10T09:00:00+02:00; tags=rust -->\n\n\
+                               ## Alpha idea\n<!-- eureka: created=2026-10-12T09:00:00+02:00; tags=rust,cli; status=doing -->\n\n\
+                               ## Gamma idea\n<!-- eureka: created=2026-09-01T09:00:00+02:00; tags=rust -->\n\n\
+                               ## Undated idea\n";
+
+    #[test]
+    fn test_list_filters_and_sorts_by_date() {
+        static LIST_ITEM_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                date: &str,
+                id: &str,
+                title: &str,
+                tags: &[String],
+            ) -> io::Result<()> {
+                let counter = LIST_ITEM_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => {
+                        assert_eq!(date, "2026-10-12");
+                        assert_eq!(id, "4ab4aa5");
+                        assert_eq!(title, "Alpha idea");
+                        assert_eq!(tags, &["rust", "cli"]);
+                    }
+                    1 => {
+                        assert_eq!(date, "2026-10-10");
+                        assert_eq!(id, "28ccc65");
+                        assert_eq!(title, "Beta idea");
+                        assert_eq!(tags, &["rust"]);
+                    }
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            IdeaRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::List(ListOptions {
+            since: chrono::NaiveDate::from_ymd_opt(2026, 10, 1),
+            tags: vec![String::from("rust")],
+            ..ListOptions::default()
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(2, &LIST_ITEM_COUNTER));
+    }
+
+    #[test]
+    fn test_list_sorts_by_title_with_limit() {
+        static LIST_ITEM_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                date: &str,
+                _id: &str,
+                title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                let counter = LIST_ITEM_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => assert_eq!(title, "Undated idea"),
+                    1 => {
+                        assert_eq!(title, "Gamma idea");
+                        assert_eq!(date, "2026-09-01");
+                    }
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            IdeaRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::List(ListOptions {
+            status: Some(IdeaStatus::New),
+            limit: Some(2),
+            sort: SortBy::Title,
+            reverse: true,
+            ..ListOptions::default()
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(2, &LIST_ITEM_COUNTER));
+    }
+
+    #[test]
+    fn test_list_groups_by_status() {
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                let counter = PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    1 => assert_eq!(title, "Beta idea"),
+                    2 => assert_eq!(title, "Gamma idea"),
+                    3 => assert_eq!(title, "Undated idea"),
+                    5 => assert_eq!(title, "Alpha idea"),
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, value: &str) -> io::Result<()> {
+                let counter = PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => assert_eq!(value, "new (3)"),
+                    4 => assert_eq!(value, "doing (1)"),
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            IdeaRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::List(ListOptions {
+            group: true,
+            ..ListOptions::default()
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(6, &PRINT_COUNTER));
+    }
+
+    #[test]
+    fn test_view_filters_by_status() {
+        static OPEN_PAGER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn open_pager(&self, file_path: &str) -> io::Result<()> {
+                OPEN_PAGER_COUNTER.fetch_add(1, Ordering::SeqCst);
+                let contents = std::fs::read_to_string(file_path)?;
+                assert_eq!(contents, "# doing\n\n## Alpha idea\n\n");
+                Ok(())
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            DefaultMockPrinter {},
+            DefaultMockReader {},
+            IdeaRepoMockGit {},
+            MockProgramOpener {},
+        );
+        let command = EurekaCommand::View(ViewOptions {
+            status: Some(IdeaStatus::Doing),
+            group: true,
+            raw: true,
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &OPEN_PAGER_COUNTER));
+    }
+
+    #[test]
+    fn test_view_renders_markdown() {
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, value: &str) -> io::Result<()> {
+                PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                // Not a terminal, so it's printed without colors or metadata
+                assert_eq!(
+                    value,
+                    "Ideas\n\nBeta idea\n\nAlpha idea\n\nGamma idea\n\nUndated idea\n\n"
+                );
+                Ok(())
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            IdeaRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+
+        let actual = eureka.run(EurekaCommand::View(ViewOptions::default()));
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &PRINT_COUNTER));
+    }
+
+    #[test]
+    fn test_set_status() {
+        static COMMIT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let readme = repo_dir.path().join("README.md");
+        std::fs::write(&readme, LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error> {
+                assert_eq!(paths, [PathBuf::from("README.md")]);
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                COMMIT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(subject, "Status: Beta idea is exploring");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Status(String::from("beta"), IdeaStatus::Exploring);
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &COMMIT_COUNTER));
+        assert_eq!(
+            std::fs::read_to_string(&readme).unwrap(),
+            LIST_README.replace(
+                "created=2026-10-10T09:00:00+02:00; tags=rust -->",
+                "created=2026-10-10T09:00:00+02:00; tags=rust; status=exploring -->"
+            )
+        );
+    }
+
+    #[test]
+    fn test_set_tags() {
+        static COMMIT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let readme = repo_dir.path().join("README.md");
+        std::fs::write(&readme, LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error> {
+                assert_eq!(paths, [PathBuf::from("README.md")]);
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                COMMIT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(subject, "Tags: Beta idea is tagged #cli #tools");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Tag(
+            String::from("beta"),
+            vec![String::from("#cli"), String::from("tools")],
+        );
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &COMMIT_COUNTER));
+        assert_eq!(
+            std::fs::read_to_string(&readme).unwrap(),
+            LIST_README.replace(
+                "created=2026-10-10T09:00:00+02:00; tags=rust -->",
+                "created=2026-10-10T09:00:00+02:00; tags=cli,tools -->"
+            )
+        );
+    }
+
+    #[test]
+    fn test_capture_idea_with_tags() {
+        static ADD_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static INPUT_HEADER_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static READ_INPUT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let readme = repo_dir.path().join("README.md");
+        std::fs::write(&readme, LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                let counter = INPUT_HEADER_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => assert_eq!(
+                        value,
+                        ">> Idea summary, add tags with #tag (e.g. #rust #cli)"
+                    ),
+                    1 => assert_eq!(
+                        value,
+                        "#ru is a new tag, did you mean #rust? Enter the tag to use, or leave empty to keep #ru"
+                    ),
+                    2 => assert_eq!(
+                        value,
+                        "#c is a new tag, did you mean #cli? Enter the tag to use, or leave empty to keep #c"
+                    ),
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                let counter = READ_INPUT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => Ok(String::from("Faster builds #ru #c #perf")),
+                    // Use #rust instead of #ru
+                    1 => Ok(String::from("#rust")),
+                    // Keep #c
+                    _ => Ok(String::new()),
+                }
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error> {
+                ADD_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(paths, [PathBuf::from("README.md")]);
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                assert_eq!(subject, "Faster builds");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn open_editor_at(&self, file_path: &str, line: usize) -> io::Result<()> {
+                let contents = std::fs::read_to_string(file_path)?;
+                // The cursor is on the empty line below the new section's metadata
+                assert!(contents
+                    .lines()
+                    .nth(line - 2)
+                    .unwrap()
+                    .contains("tags=rust,c,perf"));
+                assert_eq!(contents.lines().nth(line - 1), Some(""));
+                std::fs::write(file_path, contents + "Written in the editor\n")
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            MockReader {},
+            MockGit {},
+            MockProgramOpener {},
+        );
+
+        let actual = eureka.run(EurekaCommand::Add(AddOptions::default()));
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &ADD_COUNTER));
+        assert!(counter_equals(3, &INPUT_HEADER_COUNTER));
+        let contents = std::fs::read_to_string(&readme).unwrap();
+        let section = contents.strip_prefix(LIST_README).unwrap();
+        assert!(section.starts_with("\n## Faster builds\n<!-- eureka: created="));
+        assert!(section
+            .ends_with("; tags=rust,c,perf; author=Some Name -->\n\nWritten in the editor\n"));
+    }
+
+    #[test]
+    fn test_capture_idea_with_repo_template() {
+        static ADD_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let readme = repo_dir.path().join("README.md");
+        std::fs::write(&readme, "# Ideas\n").unwrap();
+        let templates_dir = repo_dir.path().join(".eureka").join("templates");
+        std::fs::create_dir_all(&templates_dir).unwrap();
+        std::fs::write(
+            templates_dir.join("proposal.md"),
+            "### Problem\n\n### Proposal\n\n{{summary}} by {{author}} on {{branch}}\n",
+        )
+        .unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, value: &str) -> io::Result<()> {
+                assert_eq!(value, ">> Idea summary");
+                Ok(())
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockReader;
+
+        impl ReadInput for MockReader {
+            fn read_input(&mut self) -> io::Result<String> {
+                Ok(String::from("Faster builds"))
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error> {
+                ADD_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(paths, [PathBuf::from("README.md")]);
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                assert_eq!(subject, "Faster builds");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                Ok(String::from("Some Name"))
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, file_path: &str) -> io::Result<()> {
+                let contents = std::fs::read_to_string(file_path)?;
+                std::fs::write(file_path, contents + "Written in the editor\n")
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            MockReader {},
+            MockGit {},
+            MockProgramOpener {},
+        );
+
+        let actual = eureka.run(EurekaCommand::Add(AddOptions {
+            template: Some(String::from("proposal")),
+            ..AddOptions::default()
+        }));
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &ADD_COUNTER));
+        let contents = std::fs::read_to_string(&readme).unwrap();
+        assert!(contents.starts_with("# Ideas\n\n## Faster builds\n<!-- eureka: created="));
+        assert!(contents.ends_with(
+            "; author=Some Name -->\n\n### Problem\n\n### Proposal\n\nFaster builds by Some Name on main\nWritten in the editor\n"
+        ));
+    }
+
+    #[test]
+    fn test_tags_with_counts() {
+        static PRINT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, value: &str) -> io::Result<()> {
+                let counter = PRINT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => assert_eq!(value, "3  rust"),
+                    1 => assert_eq!(value, "1  cli"),
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            IdeaRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+
+        let actual = eureka.run(EurekaCommand::Tags);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(2, &PRINT_COUNTER));
+    }
+
+    #[test]
+    fn test_search_ideas_and_commits() {
+        static LIST_ITEM_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static SEARCH_MATCH_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            repo_dir.path().join("README.md"),
+            "# Ideas\n\n## Cache builds\n\nKeep the cache warm\nShare it\n\n## Unrelated\n\nNothing here\n",
+        )
+        .unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                id: &str,
+                title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                let counter = LIST_ITEM_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => {
+                        assert_eq!(id, "b4ed8df");
+                        assert_eq!(title, "Cache builds");
+                    }
+                    1 => {
+                        assert_eq!(id, "0000000");
+                        assert_eq!(title, "Add caching idea");
+                    }
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+
+            fn search_match(&mut self, line: &str, matches: &[Range<usize>]) -> io::Result<()> {
+                let counter = SEARCH_MATCH_COUNTER.fetch_add(1, Ordering::SeqCst);
+                match counter {
+                    0 => {
+                        assert_eq!(line, "Cache builds");
+                        assert_eq!(matches.len(), 1);
+                        assert_eq!(matches[0], 0..4);
+                    }
+                    1 => {
+                        assert_eq!(line, "Keep the cache warm");
+                        assert_eq!(matches.len(), 1);
+                        assert_eq!(matches[0], 9..13);
+                    }
+                    2 => {
+                        assert_eq!(line, "Add caching idea");
+                        assert_eq!(matches.len(), 1);
+                        assert_eq!(matches[0], 4..8);
+                    }
+                    _ => panic!("Unknown state"),
+                }
+                Ok(())
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                Ok(vec![None; lines.len()])
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                let time =
+                    chrono::DateTime::parse_from_rfc3339("2026-10-18T09:30:00+02:00").unwrap();
+                Ok(vec![
+                    CommitInfo {
+                        id: Oid::zero(),
+                        time,
+                        summary: String::from("Add caching idea"),
+                        message: String::from("Add caching idea"),
+                    },
+                    CommitInfo {
+                        id: Oid::zero(),
+                        time,
+                        summary: String::from("Initial commit"),
+                        message: String::from("Initial commit"),
+                    },
+                ])
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Search(SearchOptions {
+            query: String::from("CACH"),
+            ignore_case: true,
+            regex: false,
+            commits: true,
+        });
+
+        let actual = eureka.run(command);
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(2, &LIST_ITEM_COUNTER));
+        assert!(counter_equals(3, &SEARCH_MATCH_COUNTER));
+    }
+
+    #[test]
+    fn test_search_invalid_regex() {
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(String::from("specific-repo-path")),
+            DefaultMockPrinter {},
+            DefaultMockReader {},
+            IdeaRepoMockGit {},
+            DefaultMockProgramOpener {},
+        );
+        let command = EurekaCommand::Search(SearchOptions {
+            query: String::from("(unclosed"),
+            regex: true,
+            ..SearchOptions::default()
+        });
+
+        let actual = eureka.run(command).map_err(|e| e.kind());
+
+        assert_eq!(actual, Err(ErrorKind::InvalidInput));
+    }
+
+    #[test]
+    fn test_edit_idea_by_query() {
+        static ADD_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        static OPEN_EDITOR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let readme = repo_dir.path().join("README.md");
+        std::fs::write(&readme, LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error> {
+                ADD_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(paths, [PathBuf::from("README.md")]);
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                assert_eq!(subject, "Edit: Alpha idea, revised");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                Ok(vec![None; lines.len()])
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, file_path: &str) -> io::Result<()> {
+                OPEN_EDITOR_COUNTER.fetch_add(1, Ordering::SeqCst);
+                let text = std::fs::read_to_string(file_path)?;
+                // Only the matching idea is opened
+                assert!(text.starts_with("## Alpha idea\n"));
+                assert!(!text.contains("Beta idea"));
+                std::fs::write(
+                    file_path,
+                    text.replace("## Alpha idea", "## Alpha idea, revised") + "A new body\n",
+                )
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            MockProgramOpener {},
+        );
+
+        let actual = eureka.run(EurekaCommand::Edit(String::from("alpha")));
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &OPEN_EDITOR_COUNTER));
+        assert!(counter_equals(1, &ADD_COUNTER));
+        assert_eq!(
+            std::fs::read_to_string(&readme).unwrap(),
+            LIST_README.replace(
+                "## Alpha idea\n<!-- eureka: created=2026-10-12T09:00:00+02:00; tags=rust,cli; status=doing -->\n\n",
+                "## Alpha idea, revised\n<!-- eureka: created=2026-10-12T09:00:00+02:00; tags=rust,cli; status=doing -->\n\nA new body\n\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_edit_ambiguous_query() {
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                Ok(vec![None; lines.len()])
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+
+        let actual = eureka
+            .run(EurekaCommand::Edit(String::from("idea")))
+            .map_err(|e| e.kind());
+
+        assert_eq!(actual, Err(ErrorKind::InvalidInput));
+    }
+
+    #[test]
+    fn test_edit_keeps_edits_when_editor_fails() {
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                unimplemented!()
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                Ok(vec![None; lines.len()])
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        struct MockProgramOpener;
+
+        impl ProgramOpener for MockProgramOpener {
+            fn open_editor(&self, file_path: &str) -> io::Result<()> {
+                std::fs::write(file_path, "## Gamma idea\n\nHalf written\n")?;
+                Err(Error::other("Editor exited with 1"))
+            }
+
+            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            MockProgramOpener {},
+        );
+
+        let actual = eureka
+            .run(EurekaCommand::Edit(String::from("gamma")))
+            .unwrap_err()
+            .to_string();
+
+        let (_, kept_path) = actual.split_once("Your edits are kept in ").unwrap();
+        let kept = std::fs::read_to_string(kept_path).unwrap();
+        std::fs::remove_file(kept_path).unwrap();
+        assert!(actual.starts_with("Editor exited with 1"));
+        assert_eq!(kept, "## Gamma idea\n\nHalf written\n");
+        let readme = std::fs::read_to_string(repo_dir.path().join("README.md")).unwrap();
+        assert_eq!(readme, LIST_README);
+    }
+
+    #[test]
+    fn test_archive_idea() {
+        static ADD_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(repo_dir.path().join("README.md"), LIST_README).unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
                 Ok(())
             }
 
-            fn checkout_branch(&self, branch_name: &str) -> Result<(), git2::Error> {
-                assert_eq!(branch_name, "main");
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn add(&self, paths: &[PathBuf]) -> Result<(), git2::Error> {
+                ADD_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(
+                    paths,
+                    [PathBuf::from("README.md"), PathBuf::from("ARCHIVE.md")]
+                );
+                Ok(())
+            }
+
+            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
+                assert_eq!(subject, "Archive: Gamma idea");
+                Ok(Oid::zero())
+            }
+
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
+
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                Ok(vec![None; lines.len()])
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
+                unimplemented!()
+            }
+        }
+
+        let mut eureka = Eureka::new(
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
+            MockPrinter {},
+            DefaultMockReader {},
+            MockGit {},
+            DefaultMockProgramOpener {},
+        );
+
+        let actual = eureka.run(EurekaCommand::Archive(String::from("fe7a1da")));
+
+        assert!(actual.is_ok());
+        assert!(counter_equals(1, &ADD_COUNTER));
+        let readme = std::fs::read_to_string(repo_dir.path().join("README.md")).unwrap();
+        let archive = std::fs::read_to_string(repo_dir.path().join("ARCHIVE.md")).unwrap();
+        assert!(!readme.contains("Gamma idea"));
+        assert!(readme.contains("## Undated idea"));
+        assert_eq!(
+            archive,
+            "# Archived ideas\n\n## Gamma idea\n<!-- eureka: created=2026-09-01T09:00:00+02:00; tags=rust -->\n"
+        );
+    }
+
+    #[test]
+    fn test_undo_skips_undone_and_manual_commits() {
+        static REVERT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        const FIRST: &str = "1111111111111111111111111111111111111111";
+        const SECOND: &str = "2222222222222222222222222222222222222222";
+
+        // The second idea was undone already
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            repo_dir.path().join("README.md"),
+            "# Ideas\n\n## First idea\n",
+        )
+        .unwrap();
+
+        struct MockPrinter;
+
+        impl Print for MockPrinter {
+            fn print(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn println(&mut self, _value: &str) -> io::Result<()> {
+                // noop
+                Ok(())
+            }
+        }
+
+        impl PrintColor for MockPrinter {
+            fn fts_banner(&mut self) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn input_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn error(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn list_item(
+                &mut self,
+                _date: &str,
+                _id: &str,
+                _title: &str,
+                _tags: &[String],
+            ) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+                unimplemented!()
+            }
+
+            fn group_header(&mut self, _value: &str) -> io::Result<()> {
+                unimplemented!()
+            }
+        }
+
+        struct MockGit;
+
+        impl GitManagement for MockGit {
+            fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
                 Ok(())
             }
 
-            fn add(&self) -> Result<(), git2::Error> {
+            fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
                 Ok(())
             }
 
-            fn commit(&self, subject: &str) -> Result<Oid, git2::Error> {
-                assert_eq!(subject, "read-input-string");
-                Ok(Oid::zero())
+            fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+                unimplemented!()
             }
 
-            fn push(&self, branch_name: &str) -> Result<(), git2::Error> {
-                assert_eq!(branch_name, "main");
-                Ok(())
+            fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+                unimplemented!()
             }
-        }
 
-        struct MockProgramOpener;
+            fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+                Ok(())
+            }
 
-        impl ProgramOpener for MockProgramOpener {
-            fn open_editor(&self, file_path: &str) -> io::Result<()> {
-                assert_eq!(file_path, "specific-repo/README.md");
+            fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
                 Ok(())
             }
 
-            fn open_pager(&self, _file_path: &str) -> io::Result<()> {
+            fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn blame_lines(
+                &self,
+                _path: &Path,
+                _lines: &[usize],
+            ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+                unimplemented!()
+            }
+
+            fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+                let commit = |id: &str, message: &str| CommitInfo {
+                    id: Oid::from_str(id).unwrap(),
+                    time: chrono::DateTime::parse_from_rfc3339("2026-10-18T09:30:00+02:00")
+                        .unwrap(),
+                    summary: message.lines().next().unwrap().to_string(),
+                    message: message.to_string(),
+                };
+                Ok(vec![
+                    commit(
+                        "3333333333333333333333333333333333333333",
+                        &format!("Undo: Second idea\n\nThis reverts commit {}.", SECOND),
+                    ),
+                    commit(
+                        "4444444444444444444444444444444444444444",
+                        "Fix typo by hand",
+                    ),
+                    commit(SECOND, "Second idea"),
+                    commit(FIRST, "First idea"),
+                ])
+            }
+
+            fn revert(&self, id: Oid, message: &str) -> Result<Oid, git2::Error> {
+                REVERT_COUNTER.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(id, Oid::from_str(FIRST).unwrap());
+                assert_eq!(
+                    message,
+                    format!("Undo: First idea\n\nThis reverts commit {}.", FIRST)
+                );
+                Ok(Oid::zero())
+            }
+
+            fn author(&self) -> Result<String, git2::Error> {
                 unimplemented!()
             }
         }
 
         let mut eureka = Eureka::new(
-            MockConfigManager {},
+            IdeaRepoMockConfigManager(repo_dir.path().display().to_string()),
             MockPrinter {},
-            MockReader {},
+            DefaultMockReader {},
             MockGit {},
-            MockProgramOpener {},
+            DefaultMockProgramOpener {},
         );
-        let opts = EurekaOptions {
-            clear_config: false,
-            view: false,
-        };
 
-        let actual = eureka.run(opts);
+        let actual = eureka.run(EurekaCommand::Undo);
 
         assert!(actual.is_ok());
+        assert!(counter_equals(1, &REVERT_COUNTER));
     }
 
     fn counter_equals(num: u8, counter: &AtomicUsize) -> bool {
@@ -792,6 +6684,24 @@ mod tests {
         fn error(&mut self, _value: &str) -> io::Result<()> {
             unimplemented!()
         }
+
+        fn list_item(
+            &mut self,
+            _date: &str,
+            _id: &str,
+            _title: &str,
+            _tags: &[String],
+        ) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn search_match(&mut self, _line: &str, _matches: &[Range<usize>]) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn group_header(&mut self, _value: &str) -> io::Result<()> {
+            unimplemented!()
+        }
     }
 
     struct DefaultMockReader;
@@ -802,6 +6712,7 @@ mod tests {
         }
     }
 
+    #[allow(dead_code)]
     struct DefaultMockConfigManager;
 
     impl ConfigManagement for DefaultMockConfigManager {
@@ -817,6 +6728,10 @@ mod tests {
             unimplemented!()
         }
 
+        fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+            unimplemented!()
+        }
+
         fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
             unimplemented!()
         }
@@ -824,6 +6739,46 @@ mod tests {
         fn config_rm(&self) -> io::Result<()> {
             unimplemented!()
         }
+
+        fn pending_push_mark(&self) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn pending_push_clear(&self) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn pending_push_exists(&self) -> bool {
+            unimplemented!()
+        }
+
+        fn template_read(&self, _name: &str) -> io::Result<String> {
+            Err(Error::new(ErrorKind::NotFound, "some-error"))
+        }
+
+        fn profiles_read(&self) -> io::Result<Profiles> {
+            unimplemented!()
+        }
+
+        fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+            unimplemented!()
+        }
+
+        fn config_text_read(&self) -> io::Result<String> {
+            unimplemented!()
+        }
+
+        fn config_text_write(&self, _text: &str) -> io::Result<()> {
+            unimplemented!()
+        }
     }
 
     struct DefaultGit;
@@ -837,7 +6792,191 @@ mod tests {
             unimplemented!()
         }
 
-        fn add(&self) -> Result<(), git2::Error> {
+        fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+            unimplemented!()
+        }
+
+        fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+        fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn blame_lines(
+            &self,
+            _path: &Path,
+            _lines: &[usize],
+        ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+            unimplemented!()
+        }
+
+        fn author(&self) -> Result<String, git2::Error> {
+            unimplemented!()
+        }
+    }
+
+    struct MissingRepoMockGit;
+
+    impl GitManagement for MissingRepoMockGit {
+        fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+            Err(git2::Error::from_str("some-error"))
+        }
+
+        fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn commit(&self, _subject: &str) -> Result<Oid, git2::Error> {
+            unimplemented!()
+        }
+
+        fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn blame_lines(
+            &self,
+            _path: &Path,
+            _lines: &[usize],
+        ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+            unimplemented!()
+        }
+
+        fn author(&self) -> Result<String, git2::Error> {
+            unimplemented!()
+        }
+    }
+
+    // Config for an idea repo at the given path, everything else is unset
+    struct IdeaRepoMockConfigManager(String);
+
+    impl ConfigManagement for IdeaRepoMockConfigManager {
+        fn config_dir_create(&self) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn config_dir_exists(&self) -> bool {
+            true
+        }
+
+        fn config_read(&self, file: ConfigType) -> io::Result<String> {
+            match file {
+                ConfigType::Repo => Ok(self.0.clone()),
+                _ => Err(Error::new(ErrorKind::NotFound, "some-error")),
+            }
+        }
+
+        fn config_origin(&self, _file: ConfigType) -> io::Result<ConfigOrigin> {
+            unimplemented!()
+        }
+
+        fn config_write(&self, _file: ConfigType, _value: String) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn config_rm(&self) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn pending_push_mark(&self) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn pending_push_clear(&self) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn pending_push_exists(&self) -> bool {
+            false
+        }
+
+        fn template_read(&self, _name: &str) -> io::Result<String> {
+            Err(Error::new(ErrorKind::NotFound, "some-error"))
+        }
+
+        fn profiles_read(&self) -> io::Result<Profiles> {
+            unimplemented!()
+        }
+
+        fn profiles_write(&self, _profiles: Profiles) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn config_unset(&self, _config_type: ConfigType) -> io::Result<()> {
+            unimplemented!()
+        }
+
+        fn config_list(&self) -> io::Result<Vec<(ConfigType, String)>> {
+            unimplemented!()
+        }
+
+        fn config_text_read(&self) -> io::Result<String> {
+            unimplemented!()
+        }
+
+        fn config_text_write(&self, _text: &str) -> io::Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // An idea repo where nothing has been committed yet
+    struct IdeaRepoMockGit;
+
+    impl GitManagement for IdeaRepoMockGit {
+        fn init(&mut self, _repo_path: &str) -> Result<(), git2::Error> {
+            Ok(())
+        }
+
+        fn checkout_branch(&self, _branch_name: &str) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn add(&self, _paths: &[PathBuf]) -> Result<(), git2::Error> {
             unimplemented!()
         }
 
@@ -845,7 +6984,39 @@ mod tests {
             unimplemented!()
         }
 
-        fn push(&self, _branch_name: &str) -> Result<(), git2::Error> {
+        fn push(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn pull(&self, _remote_name: &str, _branch_name: &str) -> Result<(), git2::Error> {
+            unimplemented!()
+        }
+
+        fn current_branch(&self) -> Result<Option<String>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn remotes(&self) -> Result<Vec<String>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn blame_lines(
+            &self,
+            _path: &Path,
+            lines: &[usize],
+        ) -> Result<Vec<Option<CommitInfo>>, git2::Error> {
+            Ok(vec![None; lines.len()])
+        }
+
+        fn log(&self) -> Result<Vec<CommitInfo>, git2::Error> {
+            unimplemented!()
+        }
+
+        fn revert(&self, _id: Oid, _message: &str) -> Result<Oid, git2::Error> {
+            unimplemented!()
+        }
+
+        fn author(&self) -> Result<String, git2::Error> {
             unimplemented!()
         }
     }